@@ -1,11 +1,21 @@
+use crate::block_layout::merge_touching;
+use crate::border_style::{perimeter_index, perimeter_len, BorderStyleProvider};
 use crate::border_symbols::symbol_set;
-use crate::{BorderSymbol, BorderSymbolSet, Side};
+use crate::junction::Junction;
+use crate::{BorderError, BorderSymbol, BorderSymbolSet, Side};
 use dyn_clone::clone_box;
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Position, Rect};
+use ratatui::layout::{Alignment, Position, Rect};
 use ratatui::prelude::{Style, Widget};
+use ratatui::text::{Line, StyledGrapheme};
 use ratatui::widgets::BorderType;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::cmp::min;
 use std::fmt::{Debug, Formatter};
+use std::ops::{Deref, Range};
+use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
 
 ///
 /// Border for a Block.
@@ -20,13 +30,118 @@ use std::fmt::{Debug, Formatter};
 ///
 /// ![schematics](https://raw.githubusercontent.com/thscharler/ratatui-block/refs/heads/master/diagram/border_symbol_1.png)
 ///
+/// Titles are laid out around any connections to other borders.
+/// They never overwrite a connection, instead they are moved aside
+/// or truncated. Labels along the left and right border skip
+/// any connections.
 ///
 pub struct BlockBorder {
-    border_style: Style,
-    symbol_set: Box<dyn BorderSymbolSet>,
+    pub(crate) border_style: Style,
+    style_provider: Option<Box<dyn BorderStyleProvider>>,
+    pub(crate) symbol_set: Box<dyn BorderSymbolSet>,
+
+    title_style: Style,
+    title_alignment: Alignment,
+    titles: Vec<(Side, Line<'static>)>,
 
     // prebuilt border.
     pub(crate) prefab: Option<PrefabBorder>,
+    // prebuilt border resized for the last area rendered.
+    resized: RefCell<Option<Rc<PrefabBorder>>>,
+}
+
+///
+/// Result of [BlockBorder::hit].
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BorderHit {
+    /// On the border, with the side and the symbol rendered there.
+    Border(Side, BorderSymbol),
+    /// Inside the border.
+    Inside,
+}
+
+///
+/// Decides which border is shown where two areas share
+/// a border segment.
+///
+/// The segment is rendered the same by both areas, so the
+/// result doesn't depend on the order the areas are rendered.
+///
+/// This includes the junctions at both ends of the segment.
+/// Where the other border wins, the area renders these cells
+/// exactly as the other border renders them.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OverlapPolicy {
+    /// Each area renders its own border type.
+    ///
+    /// This is only deterministic if both borders are of the
+    /// same type. This is the default.
+    #[default]
+    Own,
+    /// The heavier border wins.
+    ///
+    /// The weight goes Plain = Rounded < Double < Thick <
+    /// QuadrantOutside < QuadrantInside.
+    Heavier,
+    /// The area with the given index wins. For all other segments
+    /// this falls back to [OverlapPolicy::Heavier].
+    Focused(usize),
+}
+
+impl OverlapPolicy {
+    /// Is the other border rendered on a shared segment?
+    fn other_wins(
+        &self,
+        own: usize,
+        own_border: BorderType,
+        other: usize,
+        other_border: BorderType,
+    ) -> bool {
+        if own == other {
+            return false;
+        }
+        match self {
+            OverlapPolicy::Own => false,
+            OverlapPolicy::Focused(focus) if *focus == own => false,
+            OverlapPolicy::Focused(focus) if *focus == other => true,
+            OverlapPolicy::Heavier | OverlapPolicy::Focused(_) => {
+                border_weight(other_border) > border_weight(own_border)
+            }
+        }
+    }
+}
+
+/// Weight of a border for [OverlapPolicy::Heavier].
+fn border_weight(border: BorderType) -> u8 {
+    match border {
+        BorderType::Plain => 0,
+        BorderType::Rounded => 0,
+        BorderType::Double => 1,
+        BorderType::Thick => 2,
+        BorderType::QuadrantOutside => 3,
+        BorderType::QuadrantInside => 4,
+    }
+}
+
+/// Prefab border sized for some area. Either the stored one
+/// or a resized copy.
+#[derive(Debug)]
+pub(crate) enum SizedPrefab<'a> {
+    Borrowed(&'a PrefabBorder),
+    Cached(Rc<PrefabBorder>),
+}
+
+impl Deref for SizedPrefab<'_> {
+    type Target = PrefabBorder;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            SizedPrefab::Borrowed(v) => v,
+            SizedPrefab::Cached(v) => v,
+        }
+    }
 }
 
 /// Contains the data for a prefabricated block for some specific
@@ -36,14 +151,25 @@ pub(crate) struct PrefabBorder {
     width: u16,
     height: u16,
     symbols: Vec<BorderSymbol>,
+    // style for each symbol, patched onto the border style.
+    styles: Vec<Style>,
+    // glyph of another border rendered instead of the own symbol.
+    // used for the cells of a shared segment where the other border
+    // wins the OverlapPolicy.
+    others: Vec<Option<&'static str>>,
 }
 
 impl Clone for BlockBorder {
     fn clone(&self) -> Self {
         Self {
             border_style: self.border_style,
+            style_provider: self.style_provider.as_deref().map(clone_box),
             symbol_set: clone_box(self.symbol_set.as_ref()),
+            title_style: self.title_style,
+            title_alignment: self.title_alignment,
+            titles: self.titles.clone(),
             prefab: self.prefab.clone(),
+            resized: self.resized.clone(),
         }
     }
 }
@@ -52,7 +178,11 @@ impl Debug for BlockBorder {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("BlockBorder")
             .field("border_style", &self.border_style)
+            .field("style_provider", &"..dyn..")
             .field("symbol_set", &"..dyn..")
+            .field("title_style", &self.title_style)
+            .field("title_alignment", &self.title_alignment)
+            .field("titles", &self.titles)
             .field("border", &self.prefab)
             .finish()
     }
@@ -62,8 +192,13 @@ impl Default for BlockBorder {
     fn default() -> Self {
         Self {
             border_style: Default::default(),
+            style_provider: None,
             symbol_set: symbol_set(BorderType::Plain),
+            title_style: Default::default(),
+            title_alignment: Alignment::Left,
+            titles: Default::default(),
             prefab: None,
+            resized: Default::default(),
         }
     }
 }
@@ -86,15 +221,58 @@ impl BlockBorder {
     /// [Layout::spacing] with a value `Spacing::Overlap(1)` to create the
     /// areas for such a layout.
     ///
-    /// The created border is fixed in size and will panic if you
-    /// try to render it for a different area.
+    /// The created border is built for the size of area `n`.
+    /// It can be rendered at a different size, then the connections
+    /// are moved along with their nearest corner. If they don't fit
+    /// a regular border is rendered. See [resize](BlockBorder::resize).
+    ///
+    /// Each area renders its own border type on the shared segments,
+    /// see [OverlapPolicy::Own]. Use [from_layout_with](BlockBorder::from_layout_with)
+    /// for another policy.
     ///
     /// * areas - List of all involved areas.
     /// * borders - Bordertype for each area.
     /// * select - Create the BlockBorder for area `n`.
     ///
     pub fn from_layout(areas: &[Rect], borders: &[BorderType], n: usize) -> Self {
-        create_connected_border(areas, borders, n)
+        create_connected_border(areas, borders, n, OverlapPolicy::Own)
+    }
+
+    ///
+    /// New block border for a Block that is part of some bigger layout.
+    ///
+    /// Same as [from_layout](BlockBorder::from_layout), but with
+    /// an explicit [OverlapPolicy] for the border segments shared with
+    /// other areas.
+    ///
+    pub fn from_layout_with(
+        areas: &[Rect],
+        borders: &[BorderType],
+        n: usize,
+        policy: OverlapPolicy,
+    ) -> Self {
+        create_connected_border(areas, borders, n, policy)
+    }
+
+    ///
+    /// New block border for a Block that is part of a layout where
+    /// the areas touch each other, but don't overlap.
+    ///
+    /// This is for layouts created without `Spacing::Overlap(1)`.
+    /// Adjacent areas are merged with [merge_touching] so that
+    /// the two parallel borders become one shared line, and then
+    /// connected the same way as [from_layout](BlockBorder::from_layout).
+    ///
+    /// The created border must be rendered with the merged area
+    /// `merge_touching(areas)[n]`, which may be one cell wider and/or
+    /// higher than the original area.
+    ///
+    /// * areas - List of all involved areas.
+    /// * borders - Bordertype for each area.
+    /// * select - Create the BlockBorder for area `n`.
+    ///
+    pub fn from_touching_layout(areas: &[Rect], borders: &[BorderType], n: usize) -> Self {
+        create_connected_border(&merge_touching(areas), borders, n, OverlapPolicy::Own)
     }
 
     ///
@@ -105,6 +283,22 @@ impl BlockBorder {
         self
     }
 
+    ///
+    /// Style provider for the border.
+    ///
+    /// The provider is called for every cell of the border, and
+    /// the style it returns is patched onto the border style.
+    /// Styles set with [set_style](BlockBorder::set_style) are
+    /// patched on top of that.
+    ///
+    /// See [border_style](crate::border_style) for gradients and
+    /// marching ants.
+    ///
+    pub fn border_style_provider(mut self, provider: Box<dyn BorderStyleProvider>) -> Self {
+        self.style_provider = Some(provider);
+        self
+    }
+
     ///
     /// Sets the border type used to render this border.
     ///
@@ -127,62 +321,170 @@ impl BlockBorder {
         self
     }
 
+    ///
+    /// Adds a title to the top border.
+    ///
+    /// Same as [title_top](BlockBorder::title_top).
+    ///
+    pub fn title(self, title: impl Into<Line<'static>>) -> Self {
+        self.title_top(title)
+    }
+
+    ///
+    /// Adds a title to the top border.
+    ///
+    /// Any number of titles can be added. The alignment of the
+    /// Line is used, if it has none the
+    /// [title_alignment](BlockBorder::title_alignment) is used.
+    ///
+    /// Titles don't overwrite connections to other borders,
+    /// they are moved to the next free stretch of the border,
+    /// or truncated if there is none that fits.
+    ///
+    pub fn title_top(mut self, title: impl Into<Line<'static>>) -> Self {
+        self.titles.push((Side::Top, title.into()));
+        self
+    }
+
+    ///
+    /// Adds a title to the bottom border.
+    ///
+    /// See [title_top](BlockBorder::title_top).
+    ///
+    pub fn title_bottom(mut self, title: impl Into<Line<'static>>) -> Self {
+        self.titles.push((Side::Bottom, title.into()));
+        self
+    }
+
+    ///
+    /// Adds a label to the left border.
+    ///
+    /// The label is rendered vertically, one grapheme per row.
+    /// Here the alignment `Left` means top, `Right` means bottom.
+    ///
+    /// Labels skip connections to other borders, and they are
+    /// truncated if there are not enough rows.
+    ///
+    /// Wide characters extend into the inside of the area.
+    ///
+    pub fn title_left(mut self, title: impl Into<Line<'static>>) -> Self {
+        self.titles.push((Side::Left, title.into()));
+        self
+    }
+
+    ///
+    /// Adds a label to the right border.
+    ///
+    /// See [title_left](BlockBorder::title_left).
+    ///
+    pub fn title_right(mut self, title: impl Into<Line<'static>>) -> Self {
+        self.titles.push((Side::Right, title.into()));
+        self
+    }
+
+    ///
+    /// Base style for all titles.
+    ///
+    /// The styles of the Line are patched onto this.
+    ///
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    ///
+    /// Default alignment for titles that don't have one set.
+    ///
+    pub fn title_alignment(mut self, alignment: Alignment) -> Self {
+        self.title_alignment = alignment;
+        self
+    }
+
+    ///
+    /// Highlight the outline of some other area.
+    ///
+    /// All cells of this border that lie on the outline of `outline`
+    /// get the style patched onto the border style. This includes the
+    /// junctions at the ends of a shared segment.
+    ///
+    /// Use this with the area of the focused pane for every pane of a
+    /// connected layout, and the focused pane's full outline is
+    /// highlighted no matter which border is rendered last.
+    ///
+    /// This fixates the size of the area like
+    /// [set_symbol](BlockBorder::set_symbol).
+    ///
+    /// Errors
+    ///
+    /// * [BorderError::SizeMismatch] if the border was built for a different
+    ///   size and can't be resized. The border is unchanged in that case.
+    ///
+    pub fn set_outline_style(
+        &mut self,
+        area: Rect,
+        outline: Rect,
+        style: Style,
+    ) -> Result<(), BorderError> {
+        let border = self.prefab_for(area)?;
+        border.set_outline_style(area, outline, style);
+        Ok(())
+    }
+
     ///
     /// Return the symbol at the given position along the border.
     ///
     /// When using the returned BorderSymbol you must be aware,
     /// that the corners are rendered with the top and bottom lines.
     ///
+    /// If the border was built for a different size, it is resized
+    /// for this call. See [resize](BlockBorder::resize).
+    ///
     /// __Panic__
     ///
-    /// Panics if the dimensions of the area don't match a prefabricated border.
     /// Panics if the given position doesn't lie on the border.
     ///
     #[inline]
-    #[allow(clippy::collapsible_else_if)]
-    #[allow(clippy::if_same_then_else)]
     pub fn get_symbol(&self, area: Rect, position: Position) -> BorderSymbol {
+        if let Some(border) = self.prefab_at(area) {
+            let idx = border
+                .index(area, position)
+                .expect("position not on the border");
+            border.symbols[idx]
+        } else {
+            regular_symbol(area, position).expect("position not on the border")
+        }
+    }
+
+    ///
+    /// Return the symbol at the given position along the border.
+    ///
+    /// Same as [get_symbol](BlockBorder::get_symbol) but returns
+    /// an error instead of panicking.
+    ///
+    /// Errors
+    ///
+    /// * [BorderError::DegenerateArea] if the area is less than 2 cells wide or high.
+    /// * [BorderError::OffBorder] if the position doesn't lie on the border.
+    /// * [BorderError::SizeMismatch] if the border was built for a different
+    ///   size and can't be resized.
+    ///
+    pub fn try_get_symbol(
+        &self,
+        area: Rect,
+        position: Position,
+    ) -> Result<BorderSymbol, BorderError> {
+        if area.width < 2 || area.height < 2 {
+            return Err(BorderError::DegenerateArea);
+        }
+        if !is_on_border(area, position) {
+            return Err(BorderError::OffBorder);
+        }
         if let Some(border) = self.prefab.as_ref() {
-            assert!(area.width == border.width && area.height == border.height);
-
-            if area.top() == position.y {
-                border.symbols[position.x as usize]
-            } else if area.bottom().saturating_sub(1) == position.y {
-                border.symbols
-                    [(border.width + border.height.saturating_sub(2) + position.x) as usize]
-            } else if area.right().saturating_sub(1) == position.x {
-                border.symbols[(border.width + position.y.saturating_sub(1)) as usize]
-            } else if area.left() == position.x {
-                border.symbols[(border.width * 2
-                    + border.height.saturating_sub(2)
-                    + position.y.saturating_sub(1)) as usize]
-            } else {
-                panic!("position not on the border");
-            }
+            let border = border.sized(area).map_err(|_| BorderError::SizeMismatch)?;
+            let idx = border.index(area, position).ok_or(BorderError::OffBorder)?;
+            Ok(border.symbols[idx])
         } else {
-            if area.top() == position.y {
-                if area.left() == position.x {
-                    BorderSymbol::StartCornerRegular
-                } else if area.right().saturating_sub(1) == position.x {
-                    BorderSymbol::SideRegular
-                } else {
-                    BorderSymbol::EndCornerRegular
-                }
-            } else if area.bottom().saturating_sub(1) == position.y {
-                if area.left() == position.x {
-                    BorderSymbol::StartCornerRegular
-                } else if area.right().saturating_sub(1) == position.x {
-                    BorderSymbol::SideRegular
-                } else {
-                    BorderSymbol::EndCornerRegular
-                }
-            } else if area.right().saturating_sub(1) == position.x {
-                BorderSymbol::SideRegular
-            } else if area.left() == position.x {
-                BorderSymbol::SideRegular
-            } else {
-                panic!("position not on the border");
-            }
+            regular_symbol(area, position).ok_or(BorderError::OffBorder)
         }
     }
 
@@ -190,68 +492,736 @@ impl BlockBorder {
     /// Set the symbol at the given position along the border.
     ///
     /// Setting a symbol fixates the size of the area. The border
-    /// can still be rendered at a different position, but no longer
-    /// at a different size.
+    /// can still be rendered at a different size, but then it must
+    /// be resized. See [resize](BlockBorder::resize).
+    ///
+    /// If the border was built for a different size, it is resized
+    /// first. Use [try_set_symbol](BlockBorder::try_set_symbol) if
+    /// the connections might not fit the new size.
     ///
     /// __Panic__
     ///
-    /// Panics if the dimensions of the area don't match with a previous
-    /// set_symbol() call. .
-    /// Panics if the given position doesn't lie on the border.
+    /// Panics if the given position doesn't lie on the border,
+    /// or if the border can't be resized for the area.
     ///
     #[inline]
     pub fn set_symbol(&mut self, area: Rect, position: Position, symbol: BorderSymbol) {
-        if self.prefab.is_none() {
-            self.prefab = Some(PrefabBorder::new(area));
+        let border = self.prefab_for(area).expect("border fits the area");
+        let idx = border
+            .index(area, position)
+            .expect("position not on the border");
+        border.set_symbol(idx, symbol);
+    }
+
+    ///
+    /// Set the symbol at the given position along the border.
+    ///
+    /// Same as [set_symbol](BlockBorder::set_symbol) but returns
+    /// an error instead of panicking. If the border was built for
+    /// a different size and can't be resized, this returns an
+    /// error and leaves the border unchanged.
+    ///
+    /// Errors
+    ///
+    /// * [BorderError::DegenerateArea] if the area is less than 2 cells wide or high.
+    /// * [BorderError::OffBorder] if the position doesn't lie on the border.
+    /// * [BorderError::SizeMismatch] if the border was built for a different
+    ///   size and can't be resized.
+    ///
+    pub fn try_set_symbol(
+        &mut self,
+        area: Rect,
+        position: Position,
+        symbol: BorderSymbol,
+    ) -> Result<(), BorderError> {
+        if area.width < 2 || area.height < 2 {
+            return Err(BorderError::DegenerateArea);
+        }
+        if !is_on_border(area, position) {
+            return Err(BorderError::OffBorder);
         }
+        let border = self.prefab_for(area)?;
+        let idx = border.index(area, position).ok_or(BorderError::OffBorder)?;
+        border.set_symbol(idx, symbol);
+        Ok(())
+    }
+
+    ///
+    /// Return the style for the given position along the border.
+    ///
+    /// This is the style set with [set_style](BlockBorder::set_style)
+    /// and friends, when rendering it is patched onto the
+    /// [border_style](BlockBorder::border_style).
+    ///
+    /// __Panic__
+    ///
+    /// Panics if the given position doesn't lie on the border.
+    ///
+    pub fn get_style(&self, area: Rect, position: Position) -> Style {
+        if let Some(border) = self.prefab_at(area) {
+            let idx = border
+                .index(area, position)
+                .expect("position not on the border");
+            border.styles[idx]
+        } else {
+            assert!(is_on_border(area, position), "position not on the border");
+            Style::default()
+        }
+    }
+
+    ///
+    /// Set the style for the given position along the border.
+    ///
+    /// The style is patched onto the [border_style](BlockBorder::border_style)
+    /// when rendering.
+    ///
+    /// This fixates the size of the area like
+    /// [set_symbol](BlockBorder::set_symbol).
+    ///
+    /// Errors
+    ///
+    /// * [BorderError::SizeMismatch] if the border was built for a different
+    ///   size and can't be resized. The border is unchanged in that case.
+    ///
+    /// __Panic__
+    ///
+    /// Panics if the given position doesn't lie on the border.
+    ///
+    pub fn set_style(
+        &mut self,
+        area: Rect,
+        position: Position,
+        style: Style,
+    ) -> Result<(), BorderError> {
+        let border = self.prefab_for(area)?;
+        let idx = border
+            .index(area, position)
+            .expect("position not on the border");
+        border.styles[idx] = style;
+        Ok(())
+    }
+
+    ///
+    /// Set the style for a whole side, including the corners.
+    ///
+    /// See [set_style](BlockBorder::set_style).
+    ///
+    pub fn set_side_style(
+        &mut self,
+        area: Rect,
+        side: Side,
+        style: Style,
+    ) -> Result<(), BorderError> {
+        let len = match side {
+            Side::Top | Side::Bottom => area.width,
+            Side::Right | Side::Left => area.height,
+        };
+        self.set_style_range(area, side, 0..len, style)
+    }
+
+    ///
+    /// Set the style for a range of cells along one side.
+    ///
+    /// The range is given as offsets along the side, from left
+    /// to right or top to bottom. The corners are included, so
+    /// offset 0 is the left/top corner. The range is clipped to
+    /// the length of the side.
+    ///
+    /// See [set_style](BlockBorder::set_style).
+    ///
+    pub fn set_style_range(
+        &mut self,
+        area: Rect,
+        side: Side,
+        range: Range<u16>,
+        style: Style,
+    ) -> Result<(), BorderError> {
+        let border = self.prefab_for(area)?;
+        let len = match side {
+            Side::Top | Side::Bottom => area.width,
+            Side::Right | Side::Left => area.height,
+        };
+        for i in range.start..min(range.end, len) {
+            let position = match side {
+                Side::Top => Position::new(area.x + i, area.y),
+                Side::Bottom => Position::new(area.x + i, area.bottom().saturating_sub(1)),
+                Side::Right => Position::new(area.right().saturating_sub(1), area.y + i),
+                Side::Left => Position::new(area.x, area.y + i),
+            };
+            let idx = border
+                .index(area, position)
+                .expect("position on the border");
+            border.styles[idx] = style;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Resize the border for a new area.
+    ///
+    /// Every junction is anchored to its nearest corner and
+    /// keeps its distance to that corner. The corners stay corners.
+    /// A segment shared with another border stretches or shrinks
+    /// between the junctions at its ends.
+    /// Per cell styles move with their cells, the styles in between
+    /// are stretched to the new size.
+    ///
+    /// Returns an error if the connections don't fit the new size,
+    /// or if the new area is too small for a border. The border
+    /// is unchanged in that case.
+    ///
+    /// Does nothing for a border without any connections or styles.
+    ///
+    pub fn resize(&mut self, area: Rect) -> Result<(), BorderError> {
+        if let Some(border) = self.prefab_mut() {
+            if let Cow::Owned(resized) = border.sized(area)? {
+                *border = resized;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// What lies at the given position, when the border is
+    /// rendered at area.
+    ///
+    /// Returns None if the position is outside the area.
+    /// Corners belong to the top and bottom side.
+    ///
+    pub fn hit(&self, area: Rect, position: Position) -> Option<BorderHit> {
+        if !area.contains(position) {
+            return None;
+        }
+        if !is_on_border(area, position) {
+            return Some(BorderHit::Inside);
+        }
+        let side = border_side(area, position)?;
+        let symbol = match self.try_get_symbol(area, position) {
+            Ok(symbol) => symbol,
+            Err(_) => regular_symbol(area, position)?,
+        };
+        Some(BorderHit::Border(side, symbol))
+    }
+
+    // Prefab border for the area. Creates or resizes it if necessary.
+    fn prefab_for(&mut self, area: Rect) -> Result<&mut PrefabBorder, BorderError> {
+        self.resize(area).map_err(|_| BorderError::SizeMismatch)?;
+        Ok(self.prefab.get_or_insert_with(|| PrefabBorder::new(area)))
+    }
+
+    // Prefab border for changes. Drops the resized copy.
+    pub(crate) fn prefab_mut(&mut self) -> Option<&mut PrefabBorder> {
+        self.resized.get_mut().take();
+        self.prefab.as_mut()
+    }
+
+    // Prefab border resized for the area. The resized copy is kept
+    // until the next change or the next different size.
+    //
+    // Returns None if there is no prefab border or if it can't
+    // be resized.
+    pub(crate) fn prefab_at(&self, area: Rect) -> Option<SizedPrefab<'_>> {
+        let prefab = self.prefab.as_ref()?;
+        if prefab.width == area.width && prefab.height == area.height {
+            return Some(SizedPrefab::Borrowed(prefab));
+        }
+        let mut resized = self.resized.borrow_mut();
+        if let Some(cached) = resized.as_ref() {
+            if cached.width == area.width && cached.height == area.height {
+                return Some(SizedPrefab::Cached(Rc::clone(cached)));
+            }
+        }
+        let sized = Rc::new(prefab.sized(area).ok()?.into_owned());
+        *resized = Some(Rc::clone(&sized));
+        Some(SizedPrefab::Cached(sized))
+    }
+}
+
+/// Symbol of a regular border at the given position.
+///
+/// Returns None if the position is not on the border.
+fn regular_symbol(area: Rect, position: Position) -> Option<BorderSymbol> {
+    if !is_on_border(area, position) {
+        return None;
+    }
+    let symbol = if area.top() == position.y || area.bottom().saturating_sub(1) == position.y {
+        if area.left() == position.x {
+            BorderSymbol::StartCornerRegular
+        } else if area.right().saturating_sub(1) == position.x {
+            BorderSymbol::EndCornerRegular
+        } else {
+            BorderSymbol::SideRegular
+        }
+    } else {
+        BorderSymbol::SideRegular
+    };
+    Some(symbol)
+}
+
+/// Side of the border at the given position.
+/// The corners belong to the top and bottom side.
+///
+/// Returns None if the position is not on the border.
+fn border_side(area: Rect, position: Position) -> Option<Side> {
+    if !is_on_border(area, position) {
+        None
+    } else if position.y == area.top() {
+        Some(Side::Top)
+    } else if position.y == area.bottom().saturating_sub(1) {
+        Some(Side::Bottom)
+    } else if position.x == area.right().saturating_sub(1) {
+        Some(Side::Right)
+    } else {
+        Some(Side::Left)
+    }
+}
+
+/// Does the position lie on the border of the area.
+pub(crate) fn is_on_border(area: Rect, position: Position) -> bool {
+    area.contains(position)
+        && (position.x == area.left()
+            || position.x == area.right().saturating_sub(1)
+            || position.y == area.top()
+            || position.y == area.bottom().saturating_sub(1))
+}
+
+impl Widget for BlockBorder {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &BlockBorder {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        if let Some(border) = self.prefab_at(area) {
+            render_block_prefab(
+                &border,
+                self.border_style,
+                self.style_provider.as_deref(),
+                self.symbol_set.as_ref(),
+                area,
+                buf,
+            );
+        } else {
+            render_block_direct(
+                self.border_style,
+                self.style_provider.as_deref(),
+                self.symbol_set.as_ref(),
+                area,
+                buf,
+            );
+        }
+        render_titles(self, area, buf);
+    }
+}
+
+//
+// Render the titles.
+//
+fn render_titles(block: &BlockBorder, area: Rect, buf: &mut Buffer) {
+    if block.titles.is_empty() || area.is_empty() {
+        return;
+    }
+    render_horizontal_titles(block, area, buf);
+    render_vertical_titles(block, area, buf);
+}
+
+//
+// Render the titles along the top and bottom border.
+//
+fn render_horizontal_titles(block: &BlockBorder, area: Rect, buf: &mut Buffer) {
+    for side in [Side::Top, Side::Bottom] {
+        let mut blocked = blocked_cells(block, side, area);
+        let y = if side == Side::Top {
+            area.y
+        } else {
+            area.y + area.height.saturating_sub(1)
+        };
+
+        let mut render = |pos: u16, width: u16, title: &Line<'_>| {
+            let title_area = Rect::new(area.x + pos, y, width, 1);
+            buf.set_style(title_area, block.title_style);
+            buf.set_line(title_area.x, title_area.y, title, title_area.width);
+        };
+
+        // left aligned titles go from left to right.
+        let mut cursor = 0;
+        for (_, title) in block.titles.iter().filter(|(s, t)| {
+            *s == side && t.alignment.unwrap_or(block.title_alignment) == Alignment::Left
+        }) {
+            if let Some((pos, width)) = place_title(
+                &blocked,
+                cursor..blocked.len() as u16,
+                title.width() as u16,
+                Alignment::Left,
+            ) {
+                render(pos, width, title);
+                block_title(&mut blocked, pos, width);
+                cursor = pos + width;
+            }
+        }
+
+        // right aligned titles go from right to left.
+        let mut cursor = blocked.len() as u16;
+        for (_, title) in block.titles.iter().rev().filter(|(s, t)| {
+            *s == side && t.alignment.unwrap_or(block.title_alignment) == Alignment::Right
+        }) {
+            if let Some((pos, width)) =
+                place_title(&blocked, 0..cursor, title.width() as u16, Alignment::Right)
+            {
+                render(pos, width, title);
+                block_title(&mut blocked, pos, width);
+                cursor = pos;
+            }
+        }
+
+        // centered titles are placed as one group.
+        let center = block
+            .titles
+            .iter()
+            .filter(|(s, t)| {
+                *s == side && t.alignment.unwrap_or(block.title_alignment) == Alignment::Center
+            })
+            .map(|(_, t)| t)
+            .collect::<Vec<_>>();
+        if !center.is_empty() {
+            let group_width = center.iter().map(|v| v.width() as u16 + 1).sum::<u16>() - 1;
+            if let Some((mut pos, width)) = place_title(
+                &blocked,
+                0..blocked.len() as u16,
+                group_width,
+                Alignment::Center,
+            ) {
+                let end = pos + width;
+                for title in center {
+                    if pos >= end {
+                        break;
+                    }
+                    let title_width = min(title.width() as u16, end - pos);
+                    render(pos, title_width, title);
+                    pos += title_width + 1;
+                }
+            }
+        }
+    }
+}
+
+//
+// Render the labels along the left and right border.
+//
+fn render_vertical_titles(block: &BlockBorder, area: Rect, buf: &mut Buffer) {
+    for side in [Side::Left, Side::Right] {
+        let mut blocked = blocked_cells(block, side, area);
+
+        let mut render = |cells: &[u16], graphemes: &[Option<StyledGrapheme<'_>>]| {
+            for (y, grapheme) in cells.iter().zip(graphemes.iter()) {
+                let Some(grapheme) = grapheme else {
+                    continue;
+                };
+                let y = area.y + *y;
+                // wide characters extend to the inside of the area.
+                let (x, x_hidden) = match (side, grapheme.symbol.width()) {
+                    (_, 0) => continue,
+                    (Side::Left, 1) => (area.x, None),
+                    (Side::Left, _) if area.width >= 2 => (area.x, Some(area.x + 1)),
+                    (_, 1) => (area.x + area.width.saturating_sub(1), None),
+                    (_, _) if area.width >= 2 => (
+                        area.x + area.width.saturating_sub(2),
+                        Some(area.x + area.width.saturating_sub(1)),
+                    ),
+                    (_, _) => continue,
+                };
+                if let Some(cell) = buf.cell_mut(Position::new(x, y)) {
+                    cell.set_symbol(grapheme.symbol);
+                    cell.set_style(grapheme.style);
+                }
+                if let Some(x_hidden) = x_hidden {
+                    if let Some(cell) = buf.cell_mut(Position::new(x_hidden, y)) {
+                        cell.reset();
+                    }
+                }
+            }
+        };
+
+        // top aligned labels go from top to bottom.
+        let mut cursor = 0;
+        for (_, title) in block.titles.iter().filter(|(s, t)| {
+            *s == side && t.alignment.unwrap_or(block.title_alignment) == Alignment::Left
+        }) {
+            let graphemes = label_graphemes(block, [title]);
+            let cells = place_label(&blocked, cursor, graphemes.len(), Alignment::Left);
+            render(&cells, &graphemes);
+            if let Some(last) = cells.last() {
+                block_label(&mut blocked, &cells);
+                cursor = *last;
+            }
+        }
+
+        // bottom aligned labels go from bottom to top.
+        let mut cursor = blocked.len() as u16;
+        for (_, title) in block.titles.iter().rev().filter(|(s, t)| {
+            *s == side && t.alignment.unwrap_or(block.title_alignment) == Alignment::Right
+        }) {
+            let graphemes = label_graphemes(block, [title]);
+            let mut free = blocked.clone();
+            for b in &mut free[cursor as usize..] {
+                *b = true;
+            }
+            let cells = place_label(&free, 0, graphemes.len(), Alignment::Right);
+            render(&cells, &graphemes);
+            if let Some(first) = cells.first() {
+                block_label(&mut blocked, &cells);
+                cursor = *first;
+            }
+        }
+
+        // centered labels are placed as one group.
+        let center = block.titles.iter().filter(|(s, t)| {
+            *s == side && t.alignment.unwrap_or(block.title_alignment) == Alignment::Center
+        });
+        let graphemes = label_graphemes(block, center.map(|(_, t)| t));
+        let cells = place_label(&blocked, 0, graphemes.len(), Alignment::Center);
+        render(&cells, &graphemes);
+    }
+}
+
+/// Graphemes for a group of labels, one per row.
+/// Multiple labels are separated by an empty row.
+fn label_graphemes<'a>(
+    block: &BlockBorder,
+    titles: impl IntoIterator<Item = &'a Line<'a>>,
+) -> Vec<Option<StyledGrapheme<'a>>> {
+    let mut graphemes = Vec::new();
+    for title in titles {
+        if !graphemes.is_empty() {
+            graphemes.push(None);
+        }
+        graphemes.extend(
+            title
+                .styled_graphemes(block.title_style)
+                .filter(|g| g.symbol.width() > 0)
+                .map(Some),
+        );
+    }
+    graphemes
+}
+
+/// Mark the cells used by a label as blocked.
+/// This includes one cell before and after as separator.
+fn block_label(blocked: &mut [bool], cells: &[u16]) {
+    for c in cells {
+        blocked[*c as usize] = true;
+    }
+    if let Some(first) = cells.first() {
+        blocked[first.saturating_sub(1) as usize] = true;
+    }
+    if let Some(last) = cells.last() {
+        if let Some(b) = blocked.get_mut(*last as usize + 1) {
+            *b = true;
+        }
+    }
+}
+
+/// Find the cells for a vertical label with the given number of graphemes.
+///
+/// The label skips any blocked cells and is truncated if there are not
+/// enough free cells. Searching starts at `from`.
+///
+/// Returns the position of each grapheme.
+fn place_label(blocked: &[bool], from: u16, len: usize, alignment: Alignment) -> Vec<u16> {
+    let free = (from..blocked.len() as u16)
+        .filter(|v| !blocked[*v as usize])
+        .collect::<Vec<_>>();
+    let len = min(len, free.len());
+
+    match alignment {
+        Alignment::Left => free[..len].to_vec(),
+        Alignment::Right => free[free.len() - len..].to_vec(),
+        Alignment::Center => {
+            let center = blocked.len() as u16 / 2;
+            (0..=free.len() - len)
+                .min_by_key(|start| {
+                    let first = free.get(*start).copied().unwrap_or_default();
+                    let last = free.get(start + len.saturating_sub(1)).copied();
+                    let last = last.unwrap_or_default();
+                    ((first + last) / 2).abs_diff(center)
+                })
+                .map(|start| free[start..start + len].to_vec())
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// Is this symbol a connection to some other border.
+fn is_junction(symbol: &BorderSymbol) -> bool {
+    matches!(
+        symbol,
+        BorderSymbol::SideOutward(_, _)
+            | BorderSymbol::SideInward(_, _)
+            | BorderSymbol::SideCrossed(_, _, _, _)
+    )
+}
+
+/// Glyph for a symbol of the border. Four-way junctions
+/// use [crossing](BorderSymbolSet::crossing) like the crossings
+/// of a grid, everything else the plain symbol.
+fn border_glyph(symbols: &dyn BorderSymbolSet, side: Side, symbol: BorderSymbol) -> &'static str {
+    if let BorderSymbol::SideCrossed(_, _, _, _)
+    | BorderSymbol::StartCornerCrossed(_, _, _, _)
+    | BorderSymbol::EndCornerCrossed(_, _, _, _) = symbol
+    {
+        if let Junction {
+            up: Some(up),
+            right: Some(right),
+            down: Some(down),
+            left: Some(left),
+        } = Junction::from_symbol(side, symbol, symbols.border_type())
+        {
+            return symbols.crossing(
+                up.side.unwrap_or(side),
+                up.border_type,
+                right.side.unwrap_or(side),
+                right.border_type,
+                down.side.unwrap_or(side),
+                down.border_type,
+                left.side.unwrap_or(side),
+                left.border_type,
+            );
+        }
+    }
+    symbols.symbol(side, symbol)
+}
 
-        let border = self.prefab.as_mut().expect("border");
+/// Cells along the given side that can't be used for titles.
+/// The corners are always blocked.
+fn blocked_cells(block: &BlockBorder, side: Side, area: Rect) -> Vec<bool> {
+    let len = match side {
+        Side::Top | Side::Bottom => area.width,
+        Side::Right | Side::Left => area.height,
+    } as usize;
 
-        assert!(area.width == border.width && area.height == border.height);
+    let mut blocked = vec![false; len];
+    if let Some(first) = blocked.first_mut() {
+        *first = true;
+    }
+    if let Some(last) = blocked.last_mut() {
+        *last = true;
+    }
 
-        if area.top() == position.y {
-            border.symbols[position.x as usize] = symbol;
-        } else if area.bottom().saturating_sub(1) == position.y {
-            border.symbols
-                [(border.width + border.height.saturating_sub(2) + position.x) as usize] = symbol;
-        } else if area.right().saturating_sub(1) == position.x {
-            border.symbols[(border.width + position.y.saturating_sub(1)) as usize] = symbol;
-        } else if area.left() == position.x {
-            border.symbols[(border.width * 2
-                + border.height.saturating_sub(2)
-                + position.y.saturating_sub(1)) as usize] = symbol;
-        } else {
-            panic!("position not on the border");
+    if let Some(border) = block.prefab_at(area) {
+        let (top, right, bottom, left) = border.split_render();
+        match side {
+            Side::Top => {
+                for (b, sym) in blocked.iter_mut().zip(top.iter()) {
+                    *b |= is_junction(sym);
+                }
+            }
+            Side::Bottom => {
+                for (b, sym) in blocked.iter_mut().zip(bottom.iter()) {
+                    *b |= is_junction(sym);
+                }
+            }
+            Side::Right => {
+                for (b, sym) in blocked.iter_mut().skip(1).zip(right.iter()) {
+                    *b |= is_junction(sym);
+                }
+            }
+            Side::Left => {
+                for (b, sym) in blocked.iter_mut().skip(1).zip(left.iter()) {
+                    *b |= is_junction(sym);
+                }
+            }
         }
     }
+
+    blocked
 }
 
-impl Widget for BlockBorder {
-    fn render(self, area: Rect, buf: &mut Buffer)
-    where
-        Self: Sized,
-    {
-        Widget::render(&self, area, buf);
+/// Mark the cells used by a title as blocked.
+/// This includes one cell before and after as separator.
+fn block_title(blocked: &mut [bool], pos: u16, width: u16) {
+    let start = pos.saturating_sub(1) as usize;
+    let end = min((pos + width + 1) as usize, blocked.len());
+    for b in &mut blocked[start..end] {
+        *b = true;
     }
 }
 
-impl Widget for &BlockBorder {
-    fn render(self, area: Rect, buf: &mut Buffer)
-    where
-        Self: Sized,
-    {
-        if let Some(border) = self.prefab.as_ref() {
-            render_block_prefab(
-                border,
-                self.border_style,
-                self.symbol_set.as_ref(),
-                area,
-                buf,
-            );
-        } else {
-            render_block_direct(self.border_style, self.symbol_set.as_ref(), area, buf);
+/// Stretches of free cells within the given range.
+fn free_runs(blocked: &[bool], range: Range<u16>) -> Vec<Range<u16>> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for i in range.clone() {
+        if blocked[i as usize] {
+            if let Some(s) = start.take() {
+                runs.push(s..i);
+            }
+        } else if start.is_none() {
+            start = Some(i);
         }
     }
+    if let Some(s) = start {
+        runs.push(s..range.end);
+    }
+    runs
+}
+
+/// Find a place for a title of the given width within range.
+///
+/// Uses the first free stretch that can hold the title,
+/// searching in the direction of the alignment. If there
+/// is none, the title will be truncated to fit the largest
+/// free stretch.
+///
+/// Returns the position and the usable width.
+fn place_title(
+    blocked: &[bool],
+    range: Range<u16>,
+    width: u16,
+    alignment: Alignment,
+) -> Option<(u16, u16)> {
+    if width == 0 || range.is_empty() {
+        return None;
+    }
+
+    let runs = free_runs(blocked, range);
+
+    let fit = match alignment {
+        Alignment::Left => runs
+            .iter()
+            .find(|r| r.len() >= width as usize)
+            .map(|r| (r.start, width)),
+        Alignment::Right => runs
+            .iter()
+            .rev()
+            .find(|r| r.len() >= width as usize)
+            .map(|r| (r.end - width, width)),
+        Alignment::Center => {
+            let center = blocked.len() as u16 / 2;
+            runs.iter()
+                .filter(|r| r.len() >= width as usize)
+                .map(|r| {
+                    let pos = center
+                        .saturating_sub(width / 2)
+                        .clamp(r.start, r.end - width);
+                    (pos, width)
+                })
+                .min_by_key(|(pos, width)| (pos + width / 2).abs_diff(center))
+        }
+    };
+
+    fit.or_else(|| {
+        runs.iter()
+            .rev()
+            .max_by_key(|r| r.len())
+            .map(|r| (r.start, r.len() as u16))
+    })
 }
 
 //
@@ -260,50 +1230,29 @@ impl Widget for &BlockBorder {
 pub(crate) fn render_block_prefab(
     border: &PrefabBorder,
     style: Style,
+    provider: Option<&dyn BorderStyleProvider>,
     symbols: &dyn BorderSymbolSet,
     area: Rect,
     buf: &mut Buffer,
 ) {
-    let (
-        top, //
-        right,
-        bottom,
-        left,
-    ) = border.split_render();
-
-    for (i, (top_sym, bottom_sym)) in top.iter().zip(bottom.iter()).enumerate() {
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x + i as u16, //
-            area.y,
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(symbols.symbol(Side::Top, *top_sym));
-        }
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x + i as u16,
-            area.y + area.height.saturating_sub(1),
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(symbols.symbol(Side::Bottom, *bottom_sym));
-        }
+    if area.width < 2 || area.height < 2 {
+        render_block_degenerate(style, provider, symbols, area, buf);
+        // keep the per cell styles.
+        border.for_each_cell(area, symbols, |pos, _, _, cell_style| {
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.set_style(cell_style);
+            }
+        });
+        return;
     }
 
-    for (i, (left_sym, right_sym)) in left.iter().zip(right.iter()).enumerate() {
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x, //
-            area.y + 1 + i as u16,
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(symbols.symbol(Side::Left, *left_sym));
-        }
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x + area.width.saturating_sub(1), //
-            area.y + 1 + i as u16,
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(symbols.symbol(Side::Right, *right_sym));
+    let len = perimeter_len(area);
+    border.for_each_cell(area, symbols, |pos, side, glyph, cell_style| {
+        if let Some(cell) = buf.cell_mut(pos) {
+            cell.set_style(provided_style(style, provider, area, pos, side, len).patch(cell_style));
+            cell.set_symbol(glyph);
         }
-    }
+    });
 }
 
 //
@@ -311,107 +1260,167 @@ pub(crate) fn render_block_prefab(
 //
 pub(crate) fn render_block_direct(
     style: Style,
+    provider: Option<&dyn BorderStyleProvider>,
     symbols: &dyn BorderSymbolSet,
     area: Rect,
     buf: &mut Buffer,
 ) {
-    // not prepared for a specific size.
-    if let Some(cell) = buf.cell_mut(Position::new(
-        area.x, //
-        area.y,
-    )) {
-        cell.set_style(style);
-        cell.set_symbol(symbols.symbol(Side::Top, BorderSymbol::StartCornerRegular));
+    if area.width < 2 || area.height < 2 {
+        render_block_degenerate(style, provider, symbols, area, buf);
+        return;
     }
 
+    let len = perimeter_len(area);
+    let mut set_cell = |pos: Position, side: Side, symbol: &'static str| {
+        if let Some(cell) = buf.cell_mut(pos) {
+            cell.set_style(provided_style(style, provider, area, pos, side, len));
+            cell.set_symbol(symbol);
+        }
+    };
+
+    let x2 = area.x + area.width.saturating_sub(1);
+    let y2 = area.y + area.height.saturating_sub(1);
+
+    // not prepared for a specific size.
+    set_cell(
+        Position::new(area.x, area.y),
+        Side::Top,
+        symbols.symbol(Side::Top, BorderSymbol::StartCornerRegular),
+    );
+
     let top_sym = symbols.symbol(Side::Top, BorderSymbol::SideRegular);
     let bottom_sym = symbols.symbol(Side::Bottom, BorderSymbol::SideRegular);
     for x in 1..area.width.saturating_sub(1) {
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x + x, //
-            area.y,
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(top_sym);
-        }
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x + x,
-            area.y + area.height.saturating_sub(1),
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(bottom_sym);
-        }
-    }
-
-    if let Some(cell) = buf.cell_mut(Position::new(
-        area.x + area.width.saturating_sub(1), //
-        area.y,
-    )) {
-        cell.set_style(style);
-        cell.set_symbol(symbols.symbol(Side::Top, BorderSymbol::EndCornerRegular));
+        set_cell(Position::new(area.x + x, area.y), Side::Top, top_sym);
+        set_cell(Position::new(area.x + x, y2), Side::Bottom, bottom_sym);
     }
 
-    if let Some(cell) = buf.cell_mut(Position::new(
-        area.x,
-        area.y + area.height.saturating_sub(1),
-    )) {
-        cell.set_style(style);
-        cell.set_symbol(symbols.symbol(Side::Bottom, BorderSymbol::StartCornerRegular));
-    }
+    set_cell(
+        Position::new(x2, area.y),
+        Side::Top,
+        symbols.symbol(Side::Top, BorderSymbol::EndCornerRegular),
+    );
+    set_cell(
+        Position::new(area.x, y2),
+        Side::Bottom,
+        symbols.symbol(Side::Bottom, BorderSymbol::StartCornerRegular),
+    );
 
     let left_sym = symbols.symbol(Side::Left, BorderSymbol::SideRegular);
     let right_sym = symbols.symbol(Side::Right, BorderSymbol::SideRegular);
     for y in 1..area.height.saturating_sub(1) {
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x, //
-            area.y + y,
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(left_sym);
-        }
-        if let Some(cell) = buf.cell_mut(Position::new(
-            area.x + area.width.saturating_sub(1), //
-            area.y + y,
-        )) {
-            cell.set_style(style);
-            cell.set_symbol(right_sym);
+        set_cell(Position::new(area.x, area.y + y), Side::Left, left_sym);
+        set_cell(Position::new(x2, area.y + y), Side::Right, right_sym);
+    }
+
+    set_cell(
+        Position::new(x2, y2),
+        Side::Bottom,
+        symbols.symbol(Side::Bottom, BorderSymbol::EndCornerRegular),
+    );
+}
+
+//
+// Render an area less than 2 cells wide or high.
+//
+// Renders a single line with end caps, or a single cell glyph.
+// Empty areas render nothing.
+//
+fn render_block_degenerate(
+    style: Style,
+    provider: Option<&dyn BorderStyleProvider>,
+    symbols: &dyn BorderSymbolSet,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if area.is_empty() {
+        return;
+    }
+
+    let len = perimeter_len(area);
+    for pos in area.positions() {
+        let (side, symbol) = degenerate_symbol(symbols, area, pos);
+        if let Some(cell) = buf.cell_mut(pos) {
+            cell.set_style(provided_style(style, provider, area, pos, side, len));
+            cell.set_symbol(symbol);
         }
     }
+}
+
+//
+// Glyph for a position of an area less than 2 cells wide or high,
+// and the side it belongs to.
+//
+pub(crate) fn degenerate_symbol(
+    symbols: &dyn BorderSymbolSet,
+    area: Rect,
+    position: Position,
+) -> (Side, &'static str) {
+    if area.width == 1 && area.height == 1 {
+        (Side::Top, symbols.single_cell())
+    } else if area.height == 1 {
+        let x = position.x - area.x;
+        let symbol = if x == 0 {
+            symbols.line_end(Side::Left)
+        } else if x == area.width - 1 {
+            symbols.line_end(Side::Right)
+        } else {
+            symbols.symbol(Side::Top, BorderSymbol::SideRegular)
+        };
+        (Side::Top, symbol)
+    } else {
+        let y = position.y - area.y;
+        let symbol = if y == 0 {
+            symbols.line_end(Side::Top)
+        } else if y == area.height - 1 {
+            symbols.line_end(Side::Bottom)
+        } else {
+            symbols.symbol(Side::Left, BorderSymbol::SideRegular)
+        };
+        (Side::Left, symbol)
+    }
+}
 
-    if let Some(cell) = buf.cell_mut(Position::new(
-        area.x + area.width.saturating_sub(1),
-        area.y + area.height.saturating_sub(1),
-    )) {
-        cell.set_style(style);
-        cell.set_symbol(symbols.symbol(Side::Bottom, BorderSymbol::EndCornerRegular));
+// Border style with the style of the provider patched on.
+#[inline]
+fn provided_style(
+    style: Style,
+    provider: Option<&dyn BorderStyleProvider>,
+    area: Rect,
+    position: Position,
+    side: Side,
+    len: usize,
+) -> Style {
+    if let Some(provider) = provider {
+        let index = perimeter_index(area, position);
+        style.patch(provider.style(area, position, side, index, len))
+    } else {
+        style
     }
 }
 
 impl PrefabBorder {
     /// Fill in a regular border for the given area.
+    ///
+    /// The top and bottom side each have as many cells as the
+    /// area is wide. They start with a start corner if the area
+    /// is at least 1 cell wide, and end with an end corner if it
+    /// is at least 2 cells wide. The left and right side hold the
+    /// cells between the corners, they are empty for areas less
+    /// than 3 cells high.
     pub(crate) fn new(area: Rect) -> Self {
         let mut symbols = Vec::with_capacity(area.width as usize * 2 + area.height as usize * 2);
-        symbols.push(BorderSymbol::StartCornerRegular);
-        if area.width > 2 {
-            for _ in 0..area.width - 2 {
-                symbols.push(BorderSymbol::SideRegular)
+        for _ in 0..2 {
+            if area.width > 0 {
+                symbols.push(BorderSymbol::StartCornerRegular);
             }
-        }
-        symbols.push(BorderSymbol::EndCornerRegular);
-        if area.height > 2 {
-            for _ in 0..area.height - 2 {
+            for _ in 0..area.width.saturating_sub(2) {
                 symbols.push(BorderSymbol::SideRegular)
             }
-        }
-        symbols.push(BorderSymbol::StartCornerRegular);
-        if area.width > 2 {
-            for _ in 0..area.width - 2 {
-                symbols.push(BorderSymbol::SideRegular)
+            if area.width > 1 {
+                symbols.push(BorderSymbol::EndCornerRegular);
             }
-        }
-        symbols.push(BorderSymbol::EndCornerRegular);
-        if area.height > 2 {
-            for _ in 0..area.height - 2 {
+            for _ in 0..area.height.saturating_sub(2) {
                 symbols.push(BorderSymbol::SideRegular)
             }
         }
@@ -419,6 +1428,8 @@ impl PrefabBorder {
         PrefabBorder {
             width: area.width,
             height: area.height,
+            styles: vec![Style::default(); symbols.len()],
+            others: vec![None; symbols.len()],
             symbols,
         }
     }
@@ -428,11 +1439,15 @@ impl PrefabBorder {
     ///
     /// (top_left, top, top_right, right, bottom_left, bottom, bottom_right, left)
     ///
+    /// Returns None for areas less than 2 cells wide or high.
+    /// They don't have all four corners, and are rendered as a
+    /// plain line anyway.
+    ///
     #[allow(clippy::type_complexity)]
     #[inline(always)]
     pub(crate) fn split_mut(
         &mut self,
-    ) -> (
+    ) -> Option<(
         &mut BorderSymbol,
         &mut [BorderSymbol],
         &mut BorderSymbol,
@@ -441,7 +1456,11 @@ impl PrefabBorder {
         &mut [BorderSymbol],
         &mut BorderSymbol,
         &mut [BorderSymbol],
-    ) {
+    )> {
+        if self.width < 2 || self.height < 2 {
+            return None;
+        }
+
         let (top_left, rest) = self.symbols.split_at_mut(1);
         let (top, rest) = rest.split_at_mut(self.width.saturating_sub(2) as usize);
         let (top_right, rest) = rest.split_at_mut(1);
@@ -453,7 +1472,7 @@ impl PrefabBorder {
 
         debug_assert!(rest.is_empty());
 
-        (
+        Some((
             &mut top_left[0],
             top,
             &mut top_right[0],
@@ -462,9 +1481,180 @@ impl PrefabBorder {
             bottom,
             &mut bottom_right[0],
             left,
+        ))
+    }
+
+    ///
+    /// The border resized for the area.
+    ///
+    /// Junctions are anchored to the nearest corner, shared
+    /// segments stretch between them.
+    /// Borrows self if the size matches.
+    ///
+    pub(crate) fn sized(&self, area: Rect) -> Result<Cow<'_, PrefabBorder>, BorderError> {
+        if self.width == area.width && self.height == area.height {
+            return Ok(Cow::Borrowed(self));
+        }
+        if area.width < 2 || area.height < 2 || self.width < 2 || self.height < 2 {
+            return Err(BorderError::DegenerateArea);
+        }
+
+        let mut resized = PrefabBorder::new(area);
+        let (old_corners, old_sides) = self.parts();
+        let (new_corners, new_sides) = resized.parts();
+
+        for (old, new) in old_corners.into_iter().zip(new_corners) {
+            resized.symbols[new] = self.symbols[old];
+            resized.styles[new] = self.styles[old];
+            resized.others[new] = self.others[old];
+        }
+        for (old, new) in old_sides.into_iter().zip(new_sides) {
+            resize_side(
+                &self.symbols[old.clone()],
+                &self.styles[old.clone()],
+                &self.others[old],
+                &mut resized.symbols[new.clone()],
+                &mut resized.styles[new.clone()],
+                &mut resized.others[new],
+            )?;
+        }
+
+        Ok(Cow::Owned(resized))
+    }
+
+    /// Indexes of the corners and ranges of the sides without the corners.
+    ///
+    /// ([top_left, top_right, bottom_left, bottom_right], [top, right, bottom, left])
+    ///
+    fn parts(&self) -> ([usize; 4], [Range<usize>; 4]) {
+        let w = self.width as usize;
+        let h = self.height as usize;
+        (
+            [0, w - 1, w + h - 2, 2 * w + h - 3],
+            [
+                1..w - 1,
+                w..w + h - 2,
+                w + h - 1..2 * w + h - 3,
+                2 * w + h - 2..2 * w + 2 * h - 4,
+            ],
         )
     }
 
+    ///
+    /// Index into symbols/styles for a position when rendered at area.
+    ///
+    /// Returns None if the position is not on the border.
+    ///
+    pub(crate) fn index(&self, area: Rect, position: Position) -> Option<usize> {
+        if !area.contains(position) {
+            return None;
+        }
+        let x = position.x - area.x;
+        let y = position.y - area.y;
+        let width = self.width;
+        let height = self.height;
+
+        let idx = if y == 0 {
+            x
+        } else if y == height.saturating_sub(1) {
+            width + height.saturating_sub(2) + x
+        } else if x == width.saturating_sub(1) {
+            width + y - 1
+        } else if x == 0 {
+            width * 2 + height.saturating_sub(2) + y - 1
+        } else {
+            return None;
+        };
+        Some(idx as usize)
+    }
+
+    ///
+    /// Positions of all cells of the border when rendered at area.
+    /// In the same order as the symbols.
+    ///
+    /// The corners are reported with the top and bottom side.
+    /// Empty areas have no cells.
+    ///
+    pub(crate) fn positions(&self, area: Rect) -> impl Iterator<Item = (Position, Side)> {
+        let x2 = area.x + area.width.saturating_sub(1);
+        let y2 = area.y + area.height.saturating_sub(1);
+        let (width, inner) = if area.is_empty() {
+            (0, 1..1)
+        } else {
+            (self.width, 1..1 + self.height.saturating_sub(2))
+        };
+
+        (0..width)
+            .map(move |x| (Position::new(area.x + x, area.y), Side::Top))
+            .chain(
+                inner
+                    .clone()
+                    .map(move |y| (Position::new(x2, area.y + y), Side::Right)),
+            )
+            .chain((0..width).map(move |x| (Position::new(area.x + x, y2), Side::Bottom)))
+            .chain(inner.map(move |y| (Position::new(area.x, area.y + y), Side::Left)))
+    }
+
+    ///
+    /// Calls f for each cell of the border when rendered at area,
+    /// with the glyph from the symbol set.
+    ///
+    /// The corners are reported with the top and bottom side.
+    ///
+    pub(crate) fn for_each_cell(
+        &self,
+        area: Rect,
+        symbols: &dyn BorderSymbolSet,
+        mut f: impl FnMut(Position, Side, &'static str, Style),
+    ) {
+        for ((((pos, side), sym), style), other) in self
+            .positions(area)
+            .zip(self.symbols.iter())
+            .zip(self.styles.iter())
+            .zip(self.others.iter())
+        {
+            let glyph = match other {
+                Some(glyph) => glyph,
+                None => border_glyph(symbols, side, *sym),
+            };
+            f(pos, side, glyph, *style);
+        }
+    }
+
+    /// Side for an index into symbols/styles.
+    fn side(&self, idx: usize) -> Side {
+        let w = self.width as usize;
+        let h = self.height.saturating_sub(2) as usize;
+        if idx < w {
+            Side::Top
+        } else if idx < w + h {
+            Side::Right
+        } else if idx < 2 * w + h {
+            Side::Bottom
+        } else {
+            Side::Left
+        }
+    }
+
+    /// Set the symbol at the index, and drop any other
+    /// symbol rendered there.
+    fn set_symbol(&mut self, idx: usize, symbol: BorderSymbol) {
+        self.symbols[idx] = symbol;
+        self.others[idx] = None;
+    }
+
+    ///
+    /// Set the style for all cells that lie on the outline
+    /// of another area.
+    ///
+    pub(crate) fn set_outline_style(&mut self, area: Rect, outline: Rect, style: Style) {
+        for ((pos, _), cell_style) in self.positions(area).zip(self.styles.iter_mut()) {
+            if is_on_border(outline, pos) {
+                *cell_style = cell_style.patch(style);
+            }
+        }
+    }
+
     ///
     /// Split into border parts.
     ///
@@ -491,6 +1681,136 @@ impl PrefabBorder {
     }
 }
 
+/// A cell of a side that keeps its place when resizing.
+struct Anchor {
+    old: isize,
+    new: isize,
+    /// The cells after the anchor belong to a shared segment.
+    run: Option<BorderSymbol>,
+}
+
+/// Resize one side of the border, without the corners.
+///
+/// Each junction keeps its distance to the nearest corner.
+/// A segment shared with another border stretches or shrinks
+/// between the junctions at its ends. Styles move with their
+/// cells, the cells in between are stretched.
+fn resize_side(
+    old: &[BorderSymbol],
+    old_styles: &[Style],
+    old_others: &[Option<&'static str>],
+    new: &mut [BorderSymbol],
+    new_styles: &mut [Style],
+    new_others: &mut [Option<&'static str>],
+) -> Result<(), BorderError> {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+
+    let place = |i: isize| {
+        let j = if 2 * i < n { i } else { m - (n - i) };
+        if (0..m).contains(&j) {
+            Ok(j)
+        } else {
+            Err(BorderError::SizeMismatch)
+        }
+    };
+    let run_at = |i: isize| {
+        old.get(i as usize)
+            .copied()
+            .filter(|sym| matches!(sym, BorderSymbol::SideOverlap(_, _)))
+    };
+    let is_junction = |i: isize| {
+        old.get(i as usize).is_some_and(|sym| {
+            !matches!(
+                sym,
+                BorderSymbol::SideRegular | BorderSymbol::SideOverlap(_, _)
+            )
+        })
+    };
+
+    let mut anchors = vec![Anchor {
+        old: -1,
+        new: -1,
+        run: run_at(0),
+    }];
+    let mut i = 0;
+    while i < n {
+        let sym = old[i as usize];
+        match sym {
+            BorderSymbol::SideRegular => {
+                i += 1;
+            }
+            BorderSymbol::SideOverlap(_, _) => {
+                let mut end = i + 1;
+                while end < n && old[end as usize] == sym {
+                    end += 1;
+                }
+                // the ends of a run that don't touch a junction are anchored too.
+                let start_free = i > 0 && !is_junction(i - 1);
+                let end_free = end < n && !is_junction(end);
+                if start_free {
+                    anchors.push(Anchor {
+                        old: i,
+                        new: place(i)?,
+                        run: (!end_free || end - i > 1).then_some(sym),
+                    });
+                }
+                if end_free && !(start_free && end - i == 1) {
+                    anchors.push(Anchor {
+                        old: end - 1,
+                        new: place(end - 1)?,
+                        run: None,
+                    });
+                }
+                i = end;
+            }
+            _ => {
+                anchors.push(Anchor {
+                    old: i,
+                    new: place(i)?,
+                    run: run_at(i + 1),
+                });
+                i += 1;
+            }
+        }
+    }
+    anchors.push(Anchor {
+        old: n,
+        new: m,
+        run: None,
+    });
+
+    if anchors.windows(2).any(|w| w[1].new <= w[0].new) {
+        return Err(BorderError::SizeMismatch);
+    }
+
+    for w in anchors.windows(2) {
+        let (a, b) = (&w[0], &w[1]);
+        if a.old >= 0 {
+            let (i, j) = (a.old as usize, a.new as usize);
+            new[j] = old[i];
+            new_styles[j] = old_styles[i];
+            new_others[j] = old_others[i];
+        }
+
+        let old_len = b.old - a.old - 1;
+        let new_len = b.new - a.new - 1;
+        for k in 0..new_len {
+            let j = (a.new + 1 + k) as usize;
+            new[j] = a.run.unwrap_or(BorderSymbol::SideRegular);
+            if old_len > 0 {
+                let i = (a.old + 1 + k * old_len / new_len) as usize;
+                new_styles[j] = old_styles[i];
+                new_others[j] = old_others[i];
+            } else if let Some(i) = [a.old, b.old].into_iter().find(|i| (0..n).contains(i)) {
+                new_styles[j] = old_styles[i as usize];
+            }
+        }
+    }
+
+    Ok(())
+}
+
 ///
 /// New block border for the given area.
 ///
@@ -500,8 +1820,13 @@ impl PrefabBorder {
 fn create_default_border(area: Rect) -> BlockBorder {
     BlockBorder {
         border_style: Default::default(),
+        style_provider: None,
         symbol_set: symbol_set(BorderType::Plain),
+        title_style: Default::default(),
+        title_alignment: Alignment::Left,
+        titles: Default::default(),
         prefab: Some(PrefabBorder::new(area)),
+        resized: Default::default(),
     }
 }
 
@@ -512,7 +1837,119 @@ fn create_default_border(area: Rect) -> BlockBorder {
 /// This border has all the necessary connections to the
 /// other borders.
 #[inline]
-fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) -> BlockBorder {
+fn create_connected_border(
+    areas: &[Rect],
+    borders: &[BorderType],
+    n: usize,
+    policy: OverlapPolicy,
+) -> BlockBorder {
+    connect_border(areas, borders, n, &|_| (0..areas.len()).collect(), policy)
+}
+
+/// Create a connected border.
+///
+/// Same as [create_connected_border], but only the areas returned
+/// by `neighbours` are tested for connections. It must list each
+/// area that touches the given area once.
+pub(crate) fn connect_border(
+    areas: &[Rect],
+    borders: &[BorderType],
+    n: usize,
+    neighbours: &dyn Fn(usize) -> Vec<usize>,
+    policy: OverlapPolicy,
+) -> BlockBorder {
+    let mut block = connect_symbols(areas, borders, n, neighbours(n), policy);
+    connect_overlaps(
+        &mut block,
+        areas,
+        borders,
+        n,
+        &neighbours(n),
+        policy,
+        &|i| Cow::Owned(connect_symbols(areas, borders, i, neighbours(i), policy)),
+    );
+    block
+}
+
+/// Render the cells of the shared segments where another
+/// border wins the [OverlapPolicy] as that border does.
+///
+/// `block` is the border of area `n` as created by [connect_symbols],
+/// `other` gives the same for the other areas. It is only called
+/// for the areas that win some cell.
+pub(crate) fn connect_overlaps<'a>(
+    block: &mut BlockBorder,
+    areas: &[Rect],
+    borders: &[BorderType],
+    n: usize,
+    neighbours: &[usize],
+    policy: OverlapPolicy,
+    other: &dyn Fn(usize) -> Cow<'a, BlockBorder>,
+) {
+    let area = areas[n];
+    if area.width < 2 || area.height < 2 {
+        return;
+    }
+    let Some(prefab) = block.prefab_mut() else {
+        return;
+    };
+
+    // find the border that wins each shared cell.
+    let mut winners: Vec<Option<usize>> = vec![None; prefab.symbols.len()];
+    let mut found = Vec::new();
+    for i in neighbours.iter().copied() {
+        let test = areas[i];
+        if test.width < 2 || test.height < 2 || !policy.other_wins(n, borders[n], i, borders[i]) {
+            continue;
+        }
+        for (idx, (pos, _)) in prefab.positions(area).enumerate() {
+            if !test.contains(pos) || !is_on_border(test, pos) {
+                continue;
+            }
+            match winners[idx] {
+                Some(w) if !policy.other_wins(w, borders[w], i, borders[i]) => {}
+                _ => {
+                    winners[idx] = Some(i);
+                    found.push(i);
+                }
+            }
+        }
+    }
+    found.sort_unstable();
+    found.dedup();
+
+    // and render these cells as the winner does.
+    for i in found {
+        let other = other(i);
+        let Some(other_prefab) = other.prefab.as_ref() else {
+            continue;
+        };
+        let symbols = other.symbol_set.as_ref();
+        for (idx, (pos, _)) in prefab.positions(area).enumerate() {
+            if winners[idx] != Some(i) {
+                continue;
+            }
+            if let Some(other_idx) = other_prefab.index(areas[i], pos) {
+                prefab.others[idx] = Some(border_glyph(
+                    symbols,
+                    other_prefab.side(other_idx),
+                    other_prefab.symbols[other_idx],
+                ));
+            }
+        }
+    }
+}
+
+/// Create the symbols of a connected border.
+///
+/// Each area of `neighbours` is tested for connections.
+pub(crate) fn connect_symbols(
+    areas: &[Rect],
+    borders: &[BorderType],
+    n: usize,
+    neighbours: impl IntoIterator<Item = usize>,
+    policy: OverlapPolicy,
+) -> BlockBorder {
     let own_border = borders[n];
     let area = areas[n];
     let area_x1 = area.x;
@@ -522,7 +1959,8 @@ fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) ->
 
     let mut block = create_default_border(area).border_type(own_border);
 
-    let (
+    // degenerate areas are rendered as a plain line.
+    let Some((
         top_left, //
         top,
         top_right,
@@ -531,10 +1969,69 @@ fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) ->
         bottom,
         bottom_right,
         left,
-    ) = block.prefab.as_mut().expect("border").split_mut();
+    )) = block.prefab_mut().expect("border").split_mut()
+    else {
+        return block;
+    };
 
-    for (i, test) in areas.iter().enumerate() {
+    for i in neighbours {
+        let test = &areas[i];
         let other_border = borders[i];
+        let overlap = policy.other_wins(n, own_border, i, other_border);
+
+        // degenerate areas only connect where their line runs
+        // into the border.
+        if test.width == 1 && test.height >= 2 {
+            let (x, y1, y2) = (test.x, test.y, test.bottom() - 1);
+            if (area_x1..=area_x2).contains(&x) {
+                if y1 < area_y1 && area_y1 <= y2 {
+                    let sym = if x == area_x1 {
+                        &mut *top_left
+                    } else if x == area_x2 {
+                        &mut *top_right
+                    } else {
+                        &mut top[(x - area_x1 - 1) as usize]
+                    };
+                    sym.join_outward(Side::Left, other_border);
+                }
+                if y1 <= area_y2 && area_y2 < y2 {
+                    let sym = if x == area_x1 {
+                        &mut *bottom_left
+                    } else if x == area_x2 {
+                        &mut *bottom_right
+                    } else {
+                        &mut bottom[(x - area_x1 - 1) as usize]
+                    };
+                    sym.join_outward(Side::Left, other_border);
+                }
+            }
+            continue;
+        } else if test.height == 1 && test.width >= 2 {
+            let (y, x1, x2) = (test.y, test.x, test.right() - 1);
+            if (area_y1..=area_y2).contains(&y) {
+                if x1 < area_x1 && area_x1 <= x2 {
+                    if y == area_y1 {
+                        top_left.prolong(Side::Top, other_border);
+                    } else if y == area_y2 {
+                        bottom_left.prolong(Side::Top, other_border);
+                    } else {
+                        left[(y - area_y1 - 1) as usize].join_outward(Side::Top, other_border);
+                    }
+                }
+                if x1 <= area_x2 && area_x2 < x2 {
+                    if y == area_y1 {
+                        top_right.prolong(Side::Top, other_border);
+                    } else if y == area_y2 {
+                        bottom_right.prolong(Side::Top, other_border);
+                    } else {
+                        right[(y - area_y1 - 1) as usize].join_outward(Side::Top, other_border);
+                    }
+                }
+            }
+            continue;
+        } else if test.width < 2 || test.height < 2 {
+            continue;
+        }
 
         let x1 = test.x;
         let y1 = test.y;
@@ -554,6 +2051,7 @@ fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) ->
                 Side::Bottom,
                 Side::Left,
                 other_border,
+                overlap,
             );
         }
         // test below
@@ -569,6 +2067,7 @@ fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) ->
                 Side::Top,
                 Side::Left,
                 other_border,
+                overlap,
             )
         }
         // test left
@@ -584,6 +2083,7 @@ fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) ->
                 Side::Right,
                 Side::Top,
                 other_border,
+                overlap,
             )
         } // test right
         if x1 == area_x2 {
@@ -598,6 +2098,7 @@ fn create_connected_border(areas: &[Rect], borders: &[BorderType], n: usize) ->
                 Side::Left,
                 Side::Top,
                 other_border,
+                overlap,
             )
         }
     }
@@ -618,6 +2119,7 @@ fn create_horizontal_side(
     parallel_side: Side,
     perpendicular_side: Side,
     other_border: BorderType,
+    overlap: bool,
 ) {
     if p1 < area_p1 && p2 < area_p1 {
         // left out
@@ -628,65 +2130,83 @@ fn create_horizontal_side(
     } else if p1 < area_p1 && p2 < area_p2 {
         // left overhanging
         start_corner.prolong(parallel_side, other_border);
-        // for i in 0..(p2 - area_p1) {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         block[p2 - area_p1 - 1].join_outward(perpendicular_side.opposite(), other_border);
     } else if p1 < area_p1 && p2 == area_p2 {
         // right corner/right corner, overhanging to the left.
         start_corner.prolong(parallel_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.join_outward(perpendicular_side.opposite(), other_border);
     } else if p1 < area_p1 && p2 > area_p2 {
         // overhang on both sides
         start_corner.prolong(parallel_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.prolong(parallel_side, other_border);
     } else if p1 == area_p1 && p2 < area_p2 {
         // left corner/left corner, ends inside
         start_corner.join_outward(perpendicular_side, other_border);
-        // for i in 0..p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         block[p2 - area_p1 - 1].join_outward(perpendicular_side.opposite(), other_border);
     } else if p1 == area_p1 && p2 == area_p2 {
         // full overlap
         start_corner.join_outward(perpendicular_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.join_outward(perpendicular_side.opposite(), other_border);
     } else if p1 == area_p1 && p2 > area_p2 {
         // left corner/left corner, overhanging to the right.
         start_corner.join_outward(perpendicular_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.prolong(parallel_side, other_border);
     } else if p1 < area_p2 && p2 < area_p2 {
         // partial overlap
         block[p1 - area_p1 - 1].join_outward(perpendicular_side, other_border);
-        // for i in p1 - area_p1..p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[p1 - area_p1..p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         block[p2 - area_p1 - 1].join_outward(perpendicular_side.opposite(), other_border);
     } else if p1 < area_p2 && p2 == area_p2 {
         // start inside, right corner/right corner.
         block[p1 - area_p1 - 1].join_outward(perpendicular_side, other_border);
-        // for i in p1 - area_p1..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[p1 - area_p1..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.join_outward(perpendicular_side.opposite(), other_border);
     } else if p1 < area_p2 && p2 > area_p2 {
         // start inside, overhang to the right.
         block[p1 - area_p1 - 1].join_outward(perpendicular_side, other_border);
-        // for i in p1 - area_p1..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[p1 - area_p1..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.prolong(parallel_side, other_border);
     } else if p1 == area_p2 && p2 > area_p2 {
         // left corner/right corner
@@ -710,6 +2230,7 @@ fn create_vertical_side(
     parallel_side: Side,
     start_side: Side,
     other_border: BorderType,
+    overlap: bool,
 ) {
     if p1 < area_p1 && p2 < area_p1 {
         // left out
@@ -720,65 +2241,83 @@ fn create_vertical_side(
     } else if p1 < area_p1 && p2 < area_p2 {
         // left overhanging
         start_corner.join_outward(parallel_side, other_border);
-        // for i in 0..(p2 - area_p1) {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         block[p2 - area_p1 - 1].join_outward(start_side.opposite(), other_border);
     } else if p1 < area_p1 && p2 == area_p2 {
         // right corner/right corner, overhanging to the left.
         start_corner.join_outward(parallel_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.prolong(start_side.opposite(), other_border);
     } else if p1 < area_p1 && p2 > area_p2 {
         // overhang on both sides
         start_corner.join_outward(parallel_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.join_outward(parallel_side, other_border);
     } else if p1 == area_p1 && p2 < area_p2 {
         // left corner/left corner, ends inside
         start_corner.prolong(start_side, other_border);
-        // for i in 0..p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         block[p2 - area_p1 - 1].join_outward(start_side.opposite(), other_border);
     } else if p1 == area_p1 && p2 == area_p2 {
         // full overlap
         start_corner.prolong(start_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.prolong(start_side.opposite(), other_border);
     } else if p1 == area_p1 && p2 > area_p2 {
         // left corner/left corner, overhanging to the right.
         start_corner.prolong(start_side, other_border);
-        // for i in 0..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[0..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.join_outward(parallel_side, other_border);
     } else if p1 < area_p2 && p2 < area_p2 {
         // partial overlap
         block[p1 - area_p1 - 1].join_outward(start_side, other_border);
-        // for i in p1 - area_p1..p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[p1 - area_p1..p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         block[p2 - area_p1 - 1].join_outward(start_side.opposite(), other_border);
     } else if p1 < area_p2 && p2 == area_p2 {
         // start inside, right corner/right corner.
         block[p1 - area_p1 - 1].join_outward(start_side, other_border);
-        // for i in p1 - area_p1..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[p1 - area_p1..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.prolong(start_side.opposite(), other_border);
     } else if p1 < area_p2 && p2 > area_p2 {
         // start inside, overhang to the right.
         block[p1 - area_p1 - 1].join_outward(start_side, other_border);
-        // for i in p1 - area_p1..area_p2 - area_p1 - 1 {
-        //     block[i].overlap(parallel_side, other_border);
-        // }
+        if overlap {
+            for sym in &mut block[p1 - area_p1..area_p2 - area_p1 - 1] {
+                sym.overlap(parallel_side, other_border);
+            }
+        }
         end_corner.join_outward(parallel_side, other_border);
     } else if p1 == area_p2 && p2 > area_p2 {
         // left corner/right corner
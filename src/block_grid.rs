@@ -1,11 +1,13 @@
 use crate::block_border::render_block_direct;
 use crate::border_symbols::{symbol_set, PlainSymbolSet};
+use crate::junction::{Arm, Junction};
 use crate::{BorderSymbol, BorderSymbolSet, Side};
 use dyn_clone::clone_box;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Position, Rect};
-use ratatui::style::{Style, Stylize};
+use ratatui::style::Style;
 use ratatui::widgets::{BorderType, Widget};
+use std::cmp::min;
 use std::fmt::{Debug, Formatter};
 
 ///
@@ -16,19 +18,39 @@ pub struct BlockGrid {
     outer_set: Box<dyn BorderSymbolSet>,
 
     horizontal_style: Style,
-    horizontal_side: Side,
+    // None uses the top variant.
+    horizontal_side: Option<Side>,
     horizontal_set: Box<dyn BorderSymbolSet>,
 
     vertical_style: Style,
-    vertical_side: Side,
+    // None uses the left variant.
+    vertical_side: Option<Side>,
     vertical_set: Box<dyn BorderSymbolSet>,
 
+    crossing_style: Option<Style>,
+
     // x coordinates for the vertical lines.
     // relative to the area.
     vertical: Vec<u16>,
     // y coordinates for the horizontal lines.
     // relative to the area.
     horizontal: Vec<u16>,
+    // vertical lines with their own symbols and style.
+    vertical_lines: Vec<(u16, Box<dyn BorderSymbolSet>, Style)>,
+    // horizontal lines with their own symbols and style.
+    horizontal_lines: Vec<(u16, Box<dyn BorderSymbolSet>, Style)>,
+    // merged cells in grid coordinates.
+    spans: Vec<Rect>,
+    // horizontal line segments (y, x1, x2), inclusive.
+    // relative to the area.
+    horizontal_segments: Vec<(u16, u16, u16)>,
+    // vertical line segments (x, y1, y2), inclusive.
+    // relative to the area.
+    vertical_segments: Vec<(u16, u16, u16)>,
+    // boxes around the areas of a nested layout, inclusive.
+    // relative to the area. if there are any, they replace
+    // the full outer border.
+    outlines: Vec<Rect>,
 }
 
 impl Clone for BlockGrid {
@@ -42,8 +64,23 @@ impl Clone for BlockGrid {
             vertical_style: self.vertical_style,
             vertical_side: self.vertical_side,
             vertical_set: clone_box(self.vertical_set.as_ref()),
+            crossing_style: self.crossing_style,
             vertical: self.vertical.clone(),
             horizontal: self.horizontal.clone(),
+            vertical_lines: self
+                .vertical_lines
+                .iter()
+                .map(|(pos, set, style)| (*pos, clone_box(set.as_ref()), *style))
+                .collect(),
+            horizontal_lines: self
+                .horizontal_lines
+                .iter()
+                .map(|(pos, set, style)| (*pos, clone_box(set.as_ref()), *style))
+                .collect(),
+            spans: self.spans.clone(),
+            horizontal_segments: self.horizontal_segments.clone(),
+            vertical_segments: self.vertical_segments.clone(),
+            outlines: self.outlines.clone(),
         }
     }
 }
@@ -59,8 +96,29 @@ impl Debug for BlockGrid {
             .field("vertical_style", &self.vertical_style)
             .field("vertical_side", &self.vertical_side)
             .field("vertical_set", &"vertical_set")
+            .field("crossing_style", &self.crossing_style)
             .field("horizontal", &self.horizontal)
             .field("vertical", &self.vertical)
+            .field(
+                "horizontal_lines",
+                &self
+                    .horizontal_lines
+                    .iter()
+                    .map(|(pos, _, style)| (pos, style))
+                    .collect::<Vec<_>>(),
+            )
+            .field(
+                "vertical_lines",
+                &self
+                    .vertical_lines
+                    .iter()
+                    .map(|(pos, _, style)| (pos, style))
+                    .collect::<Vec<_>>(),
+            )
+            .field("spans", &self.spans)
+            .field("horizontal_segments", &self.horizontal_segments)
+            .field("vertical_segments", &self.vertical_segments)
+            .field("outlines", &self.outlines)
             .finish()
     }
 }
@@ -71,13 +129,20 @@ impl Default for BlockGrid {
             outer_style: Default::default(),
             outer_set: Box::new(PlainSymbolSet),
             horizontal_style: Default::default(),
-            horizontal_side: Side::Left,
+            horizontal_side: None,
             horizontal_set: Box::new(PlainSymbolSet),
             vertical_style: Default::default(),
-            vertical_side: Side::Top,
+            vertical_side: None,
             vertical_set: Box::new(PlainSymbolSet),
+            crossing_style: None,
             vertical: vec![],
             horizontal: vec![],
+            vertical_lines: vec![],
+            horizontal_lines: vec![],
+            spans: vec![],
+            horizontal_segments: vec![],
+            vertical_segments: vec![],
+            outlines: vec![],
         }
     }
 }
@@ -105,6 +170,28 @@ impl BlockGrid {
         create_grid(layout)
     }
 
+    /// Create a grid from any arrangement of areas.
+    ///
+    /// The areas must be separated by one cell wide gaps, but they
+    /// don't need to form a regular grid. Each area gets a line
+    /// segment around it, the segments are joined with the fitting
+    /// junctions.
+    ///
+    /// There is no full outer border, the outline of the areas along
+    /// the edges of the rendered area uses the outer border type and
+    /// style. Areas that don't fill their bounding box leave the rest
+    /// of it empty.
+    ///
+    /// The grid must be rendered with the bounding box of all areas
+    /// plus one cell for the outer border on each side. Anything
+    /// outside the rendered area is clipped.
+    ///
+    /// Areas at x or y 0 leave no room for a border and are skipped.
+    ///
+    pub fn from_nested_layout(areas: &[Rect]) -> Self {
+        create_nested_grid(areas)
+    }
+
     ///
     /// Border style for the outer border.
     ///
@@ -148,10 +235,11 @@ impl BlockGrid {
     /// top border different from the bottom border. With this
     /// you can set which rendering variant to use.
     ///
-    /// When in doubt set `Side::Top` here.
+    /// When in doubt set `Side::Top` here. This is also used
+    /// if no side is set. A side set here is used as is.
     ///
     pub fn horizontal_side(mut self, side: Side) -> Self {
-        self.horizontal_side = side;
+        self.horizontal_side = Some(side);
         self
     }
 
@@ -180,6 +268,35 @@ impl BlockGrid {
         self
     }
 
+    /// Add a horizontal grid line with its own symbols and style.
+    ///
+    /// Use this for a heavier line below a header row and similar.
+    /// The junctions with the other lines and the outer border
+    /// are chosen from the actual pair of lines that meet.
+    ///
+    /// The position given here is relative to the rendered area.
+    ///
+    pub fn horizontal_line(
+        self,
+        pos: u16,
+        border_set: impl BorderSymbolSet + 'static,
+        style: Style,
+    ) -> Self {
+        self.horizontal_line_boxed(pos, Box::new(border_set), style)
+    }
+
+    /// Add a horizontal grid line with an already boxed symbol set.
+    pub(crate) fn horizontal_line_boxed(
+        mut self,
+        pos: u16,
+        border_set: Box<dyn BorderSymbolSet>,
+        style: Style,
+    ) -> Self {
+        self.horizontal.push(pos);
+        self.horizontal_lines.push((pos, border_set, style));
+        self
+    }
+
     ///
     /// Border style for the vertical grid lines.
     ///
@@ -195,10 +312,11 @@ impl BlockGrid {
     /// left border different from the right border. With this
     /// you can set which rendering variant to use.
     ///
-    /// When in doubt set `Side::Left` here.
+    /// When in doubt set `Side::Left` here. This is also used
+    /// if no side is set. A side set here is used as is.
     ///
     pub fn vertical_side(mut self, side: Side) -> Self {
-        self.vertical_side = side;
+        self.vertical_side = Some(side);
         self
     }
 
@@ -226,6 +344,255 @@ impl BlockGrid {
         self.vertical.push(pos);
         self
     }
+
+    /// Add a vertical grid line with its own symbols and style.
+    ///
+    /// Use this for a double line before a totals column and similar.
+    /// The junctions with the other lines and the outer border
+    /// are chosen from the actual pair of lines that meet.
+    ///
+    /// The position given here is relative to the rendered area.
+    ///
+    pub fn vertical_line(
+        mut self,
+        pos: u16,
+        border_set: impl BorderSymbolSet + 'static,
+        style: Style,
+    ) -> Self {
+        self.vertical.push(pos);
+        self.vertical_lines.push((pos, Box::new(border_set), style));
+        self
+    }
+
+    ///
+    /// Style for the crossings of two grid lines.
+    ///
+    /// By default, a crossing uses the style of the horizontal line.
+    ///
+    pub fn crossing_style(mut self, style: Style) -> Self {
+        self.crossing_style = Some(style);
+        self
+    }
+
+    /// Add a horizontal line segment from x1 to x2 inclusive.
+    ///
+    /// The positions given here are relative to the rendered area.
+    /// The segment uses the style and symbols of the horizontal lines,
+    /// or those of a [horizontal_line](BlockGrid::horizontal_line)
+    /// at the same y-position.
+    ///
+    pub fn horizontal_segment(mut self, y: u16, x1: u16, x2: u16) -> Self {
+        self.horizontal_segments.push((y, x1, x2));
+        self
+    }
+
+    /// Add a vertical line segment from y1 to y2 inclusive.
+    ///
+    /// The positions given here are relative to the rendered area.
+    /// The segment uses the style and symbols of the vertical lines,
+    /// or those of a [vertical_line](BlockGrid::vertical_line)
+    /// at the same x-position.
+    ///
+    pub fn vertical_segment(mut self, x: u16, y1: u16, y2: u16) -> Self {
+        self.vertical_segments.push((x, y1, y2));
+        self
+    }
+
+    /// Merge some grid cells into one.
+    ///
+    /// The rect is given in grid coordinates. x and width count the
+    /// columns between the vertical lines, y and height count the
+    /// rows between the horizontal lines. The grid lines inside the
+    /// span are not rendered, the lines ending at the span get
+    /// the fitting junctions.
+    ///
+    pub fn span(mut self, span: Rect) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    ///
+    /// What lies at the given position, when the grid is rendered
+    /// at area.
+    ///
+    /// Returns None if the position is outside the area.
+    /// A grid from [from_nested_layout](BlockGrid::from_nested_layout)
+    /// has no full outer border, its outline is reported as lines.
+    /// Empty space outside the outlines gives None too.
+    ///
+    pub fn hit(&self, area: Rect, position: Position) -> Option<GridHit> {
+        if !area.contains(position) {
+            return None;
+        }
+        if area.width < 2 || area.height < 2 {
+            return None;
+        }
+        let x = position.x - area.x;
+        let y = position.y - area.y;
+        let pos = Position::new(x, y);
+
+        let (cols, rows, arms) = self.arms(area);
+        let cell_arms = arms.get(x, y);
+
+        if let Some((side, symbol)) = self.outer_symbol(area, x, y, cell_arms) {
+            return Some(GridHit::Border(side, symbol));
+        }
+
+        let horizontal = cell_arms[arm(Side::Left)].or(cell_arms[arm(Side::Right)]);
+        let vertical = cell_arms[arm(Side::Top)].or(cell_arms[arm(Side::Bottom)]);
+        let hit = match (horizontal, vertical) {
+            (Some(_), Some(_)) => GridHit::Junction(x, y),
+            (Some(_), None) => GridHit::Horizontal(y),
+            (None, Some(_)) => GridHit::Vertical(x),
+            (None, None) => {
+                // empty space outside the outlines of a nested layout.
+                if !self.outlines.is_empty() && !self.outlines.iter().any(|v| v.contains(pos)) {
+                    return None;
+                }
+                GridHit::Cell(
+                    (cols.iter().filter(|v| **v < x).count() as u16).checked_sub(1)?,
+                    (rows.iter().filter(|v| **v < y).count() as u16).checked_sub(1)?,
+                )
+            }
+        };
+        Some(hit)
+    }
+
+    /// Is the piece of the vertical line between column `col`
+    /// and `col + 1` in row `row` hidden by a span.
+    fn merged_vertical(&self, col: usize, row: usize) -> bool {
+        self.spans.iter().any(|span| {
+            (span.left() as usize) <= col
+                && col + 1 < span.right() as usize
+                && (span.top() as usize..span.bottom() as usize).contains(&row)
+        })
+    }
+
+    /// Is the piece of the horizontal line between row `row`
+    /// and `row + 1` in column `col` hidden by a span.
+    fn merged_horizontal(&self, col: usize, row: usize) -> bool {
+        self.spans.iter().any(|span| {
+            (span.top() as usize) <= row
+                && row + 1 < span.bottom() as usize
+                && (span.left() as usize..span.right() as usize).contains(&col)
+        })
+    }
+
+    /// Symbol set, side and style for the line.
+    fn line(&self, line: GridLine) -> (&dyn BorderSymbolSet, Side, Style) {
+        match line {
+            GridLine::Outer(side) => (self.outer_set.as_ref(), side, self.outer_style),
+            GridLine::Horizontal(y) => {
+                let side = self.horizontal_side.unwrap_or(Side::Top);
+                match self.horizontal_lines.iter().rfind(|(pos, _, _)| *pos == y) {
+                    Some((_, set, style)) => (set.as_ref(), side, *style),
+                    None => (self.horizontal_set.as_ref(), side, self.horizontal_style),
+                }
+            }
+            GridLine::Vertical(x) => {
+                let side = self.vertical_side.unwrap_or(Side::Left);
+                match self.vertical_lines.iter().rfind(|(pos, _, _)| *pos == x) {
+                    Some((_, set, style)) => (set.as_ref(), side, *style),
+                    None => (self.vertical_set.as_ref(), side, self.vertical_style),
+                }
+            }
+        }
+    }
+}
+
+///
+/// Result of [BlockGrid::hit].
+///
+/// All positions are relative to the rendered area.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridHit {
+    /// On the outer border. The corners belong to the top and
+    /// bottom side. Junctions with the grid lines are reported
+    /// as [BorderSymbol::SideInward].
+    Border(Side, BorderSymbol),
+    /// On the horizontal line at y.
+    Horizontal(u16),
+    /// On the vertical line at x.
+    Vertical(u16),
+    /// Where lines meet, at x and y.
+    Junction(u16, u16),
+    /// Inside the cell at column and row, in grid coordinates.
+    /// Cells inside a span report the column and row under the
+    /// position, not the start of the span. A hidden line counts
+    /// to the cell left of or above it.
+    Cell(u16, u16),
+}
+
+/// Which line a part of the grid belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GridLine {
+    /// Outer border at the given side.
+    Outer(Side),
+    /// Horizontal line at y.
+    Horizontal(u16),
+    /// Vertical line at x.
+    Vertical(u16),
+}
+
+/// The lines that leave each cell of the grid towards its
+/// neighbours. Indexed by Side.
+struct GridArms {
+    width: u16,
+    arms: Vec<[Option<GridLine>; 4]>,
+}
+
+fn arm(side: Side) -> usize {
+    match side {
+        Side::Top => 0,
+        Side::Right => 1,
+        Side::Bottom => 2,
+        Side::Left => 3,
+    }
+}
+
+impl GridArms {
+    fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            arms: vec![[None; 4]; width as usize * height as usize],
+        }
+    }
+
+    fn get(&self, x: u16, y: u16) -> [Option<GridLine>; 4] {
+        self.arms[y as usize * self.width as usize + x as usize]
+    }
+
+    fn set(&mut self, x: u16, y: u16, side: Side, line: GridLine) {
+        let arms = &mut self.arms[y as usize * self.width as usize + x as usize];
+        if arms[arm(side)].is_none() {
+            arms[arm(side)] = Some(line);
+        }
+    }
+
+    /// Add a horizontal line from x1 to x2 inclusive.
+    fn horizontal(&mut self, y: u16, x1: u16, x2: u16, line: GridLine) {
+        for x in x1..=x2 {
+            if x > x1 {
+                self.set(x, y, Side::Left, line);
+            }
+            if x < x2 {
+                self.set(x, y, Side::Right, line);
+            }
+        }
+    }
+
+    /// Add a vertical line from y1 to y2 inclusive.
+    fn vertical(&mut self, x: u16, y1: u16, y2: u16, line: GridLine) {
+        for y in y1..=y2 {
+            if y > y1 {
+                self.set(x, y, Side::Top, line);
+            }
+            if y < y2 {
+                self.set(x, y, Side::Bottom, line);
+            }
+        }
+    }
 }
 
 impl Widget for BlockGrid {
@@ -243,106 +610,351 @@ impl Widget for &BlockGrid {
         Self: Sized,
     {
         // render the block .
-        render_block_direct(self.outer_style, self.outer_set.as_ref(), area, buf);
+        if self.outlines.is_empty() {
+            render_block_direct(self.outer_style, None, self.outer_set.as_ref(), area, buf);
+        }
 
-        // render connections
-        for x in self.vertical.iter().copied() {
-            if x == 0 || x >= area.width.saturating_sub(2) {
-                continue;
+        if area.width < 2 || area.height < 2 {
+            return;
+        }
+
+        let (_, _, arms) = self.arms(area);
+
+        for y in 0..area.height {
+            for x in 0..area.width {
+                let pos = Position::new(area.x + x, area.y + y);
+                let cell_arms = arms.get(x, y);
+
+                if let Some((side, symbol)) = self.outer_symbol(area, x, y, cell_arms) {
+                    // connections of the outer border.
+                    if matches!(symbol, BorderSymbol::SideInward(_, _)) {
+                        if let Some(cell) = buf.cell_mut(pos) {
+                            cell.set_symbol(self.outer_set.symbol(side, symbol));
+                        }
+                    }
+                    continue;
+                }
+
+                if let Some((symbol, style)) = self.grid_symbol(cell_arms) {
+                    if let Some(cell) = buf.cell_mut(pos) {
+                        cell.set_style(style);
+                        cell.set_symbol(symbol);
+                    }
+                }
             }
-            if let Some(cell) = buf.cell_mut((area.x + x, area.y)) {
-                cell.set_symbol(self.outer_set.symbol(
-                    Side::Top,
-                    BorderSymbol::SideInward(self.vertical_side, self.vertical_set.border_type()),
-                ));
+        }
+    }
+}
+
+impl BlockGrid {
+    /// Column and row boundaries including the outer border,
+    /// and the lines leaving each cell.
+    ///
+    /// The area must be at least 2x2.
+    fn arms(&self, area: Rect) -> (Vec<u16>, Vec<u16>, GridArms) {
+        // column and row boundaries including the outer border.
+        let boundaries = |lines: &[u16], len: u16| {
+            let mut bounds = vec![0];
+            bounds.extend(
+                lines
+                    .iter()
+                    .copied()
+                    .filter(|v| *v > 0 && *v < len.saturating_sub(2)),
+            );
+            bounds.push(len - 1);
+            bounds.sort();
+            bounds.dedup();
+            bounds
+        };
+        let cols = boundaries(&self.vertical, area.width);
+        let rows = boundaries(&self.horizontal, area.height);
+
+        let mut arms = GridArms::new(area.width, area.height);
+        if self.outlines.is_empty() {
+            let (right, bottom) = (area.width - 1, area.height - 1);
+            arms.horizontal(0, 0, right, GridLine::Outer(Side::Top));
+            arms.horizontal(bottom, 0, right, GridLine::Outer(Side::Bottom));
+            arms.vertical(0, 0, bottom, GridLine::Outer(Side::Left));
+            arms.vertical(right, 0, bottom, GridLine::Outer(Side::Right));
+        }
+        for (col, x) in cols
+            .iter()
+            .copied()
+            .enumerate()
+            .skip(1)
+            .take(cols.len() - 2)
+        {
+            for (row, y) in rows.windows(2).enumerate() {
+                if !self.merged_vertical(col - 1, row) {
+                    arms.vertical(x, y[0], y[1], GridLine::Vertical(x));
+                }
             }
-            if let Some(cell) = buf.cell_mut((area.x + x, area.y + area.height.saturating_sub(1))) {
-                cell.set_symbol(self.outer_set.symbol(
-                    Side::Bottom,
-                    BorderSymbol::SideInward(self.vertical_side, self.vertical_set.border_type()),
-                ));
+        }
+        for (row, y) in rows
+            .iter()
+            .copied()
+            .enumerate()
+            .skip(1)
+            .take(rows.len() - 2)
+        {
+            for (col, x) in cols.windows(2).enumerate() {
+                if !self.merged_horizontal(col, row - 1) {
+                    arms.horizontal(y, x[0], x[1], GridLine::Horizontal(y));
+                }
             }
         }
-        for y in self.horizontal.iter().copied() {
-            if y == 0 || y >= area.height.saturating_sub(2) {
-                continue;
+
+        // lines along the edges belong to the outer border.
+        let horizontal = |y: u16| {
+            if y == 0 {
+                GridLine::Outer(Side::Top)
+            } else if y == area.height - 1 {
+                GridLine::Outer(Side::Bottom)
+            } else {
+                GridLine::Horizontal(y)
             }
-            if let Some(cell) = buf.cell_mut((area.x, area.y + y)) {
-                cell.set_symbol(self.outer_set.symbol(
-                    Side::Left,
-                    BorderSymbol::SideInward(
-                        self.horizontal_side,
-                        self.horizontal_set.border_type(),
-                    ),
-                ));
+        };
+        let vertical = |x: u16| {
+            if x == 0 {
+                GridLine::Outer(Side::Left)
+            } else if x == area.width - 1 {
+                GridLine::Outer(Side::Right)
+            } else {
+                GridLine::Vertical(x)
             }
-            if let Some(cell) = buf.cell_mut((area.x + area.width.saturating_sub(1), area.y + y)) {
-                cell.set_symbol(self.outer_set.symbol(
-                    Side::Right,
-                    BorderSymbol::SideInward(
-                        self.horizontal_side,
-                        self.horizontal_set.border_type(),
-                    ),
-                ));
+        };
+
+        for (y, x1, x2) in self.horizontal_segments.iter().copied() {
+            if y < area.height && x1 < area.width {
+                arms.horizontal(y, x1, min(x2, area.width - 1), horizontal(y));
+            }
+        }
+        for (x, y1, y2) in self.vertical_segments.iter().copied() {
+            if x < area.width && y1 < area.height {
+                arms.vertical(x, y1, min(y2, area.height - 1), vertical(x));
             }
         }
 
-        // render vertical
-        for x in self.vertical.iter().copied() {
-            if x == 0 || x >= area.width.saturating_sub(2) {
+        // boxes are closed at the edge of the area if they are clipped.
+        // a line with an area on only one side is part of the outline.
+        let inside = |x: u16, y: u16| {
+            self.outlines
+                .iter()
+                .any(|v| x > v.left() && x + 1 < v.right() && y > v.top() && y + 1 < v.bottom())
+        };
+        for outline in self.outlines.iter().copied() {
+            if outline.x >= area.width || outline.y >= area.height {
                 continue;
             }
-            for y in area.y + 1..area.y + area.height.saturating_sub(1) {
-                if let Some(cell) = buf.cell_mut(Position::new(area.x + x, y)) {
-                    cell.set_style(self.vertical_style);
-                    cell.set_symbol(
-                        self.vertical_set
-                            .symbol(self.vertical_side, BorderSymbol::SideRegular),
-                    );
+            let (x1, y1) = (outline.left(), outline.top());
+            let x2 = min(outline.right() - 1, area.width - 1);
+            let y2 = min(outline.bottom() - 1, area.height - 1);
+
+            for y in [y1, y2] {
+                for x in x1..x2 {
+                    let above = y > 0 && (inside(x, y - 1) || inside(x + 1, y - 1));
+                    let below = inside(x, y + 1) || inside(x + 1, y + 1);
+                    let line = match horizontal(y) {
+                        GridLine::Horizontal(_) if !above => GridLine::Outer(Side::Top),
+                        GridLine::Horizontal(_) if !below => GridLine::Outer(Side::Bottom),
+                        line => line,
+                    };
+                    arms.horizontal(y, x, x + 1, line);
                 }
             }
-        }
-        // render horizontal
-        for y in self.horizontal.iter().copied() {
-            if y == 0 || y >= area.height.saturating_sub(2) {
-                continue;
-            }
-            for x in area.x + 1..area.x + area.width.saturating_sub(1) {
-                if let Some(cell) = buf.cell_mut(Position::new(x, area.y + y)) {
-                    cell.set_style(self.horizontal_style);
-                    cell.set_symbol(
-                        self.horizontal_set
-                            .symbol(self.horizontal_side, BorderSymbol::SideRegular),
-                    );
+            for x in [x1, x2] {
+                for y in y1..y2 {
+                    let left = x > 0 && (inside(x - 1, y) || inside(x - 1, y + 1));
+                    let right = inside(x + 1, y) || inside(x + 1, y + 1);
+                    let line = match vertical(x) {
+                        GridLine::Vertical(_) if !left => GridLine::Outer(Side::Left),
+                        GridLine::Vertical(_) if !right => GridLine::Outer(Side::Right),
+                        line => line,
+                    };
+                    arms.vertical(x, y, y + 1, line);
                 }
             }
         }
-        // render crossings
-        for x in self.vertical.iter().copied() {
-            if x == 0 || x >= area.width.saturating_sub(2) {
-                continue;
+
+        (cols, rows, arms)
+    }
+
+    /// Side and symbol for a cell of the outer border.
+    ///
+    /// x and y are relative to the area. Returns None for the cells
+    /// inside the border, and for all cells if there is no full outer
+    /// border. Corners belong to the top and bottom side.
+    fn outer_symbol(
+        &self,
+        area: Rect,
+        x: u16,
+        y: u16,
+        arms: [Option<GridLine>; 4],
+    ) -> Option<(Side, BorderSymbol)> {
+        if !self.outlines.is_empty() {
+            return None;
+        }
+        let corner_x = x == 0 || x == area.width - 1;
+        let corner_y = y == 0 || y == area.height - 1;
+        let (side, inward) = if corner_x && corner_y {
+            let side = if y == 0 { Side::Top } else { Side::Bottom };
+            let symbol = if x == 0 {
+                BorderSymbol::StartCornerRegular
+            } else {
+                BorderSymbol::EndCornerRegular
+            };
+            return Some((side, symbol));
+        } else if y == 0 {
+            (Side::Top, arms[arm(Side::Bottom)])
+        } else if y == area.height - 1 {
+            (Side::Bottom, arms[arm(Side::Top)])
+        } else if x == 0 {
+            (Side::Left, arms[arm(Side::Right)])
+        } else if x == area.width - 1 {
+            (Side::Right, arms[arm(Side::Left)])
+        } else {
+            return None;
+        };
+        let symbol = match inward {
+            Some(line) => {
+                let (set, line_side, _) = self.line(line);
+                BorderSymbol::SideInward(line_side, set.border_type())
             }
-            for y in self.horizontal.iter().copied() {
-                if y == 0 || y >= area.height.saturating_sub(2) {
-                    continue;
-                }
-                if let Some(cell) = buf.cell_mut(Position::new(area.x + x, area.y + y)) {
-                    cell.set_style(Style::new().red());
-                    cell.set_symbol(self.horizontal_set.symbol(
-                        self.horizontal_side,
-                        BorderSymbol::SideCrossed(
-                            self.vertical_side,
-                            self.vertical_set.border_type(),
-                            self.vertical_side,
-                            self.vertical_set.border_type(),
-                        ),
-                    ));
-                }
+            None => BorderSymbol::SideRegular,
+        };
+        Some((side, symbol))
+    }
+
+    /// Symbol for a cell inside the grid with the given arms.
+    fn grid_symbol(&self, arms: [Option<GridLine>; 4]) -> Option<(&'static str, Style)> {
+        let up = arms[arm(Side::Top)];
+        let right = arms[arm(Side::Right)];
+        let down = arms[arm(Side::Bottom)];
+        let left = arms[arm(Side::Left)];
+
+        match (up, right, down, left) {
+            (None, None, None, None) => None,
+            (Some(up), Some(right), Some(down), Some(left)) => {
+                let (set, _, style) = self.line(left);
+                let (up_set, up_side, _) = self.line(up);
+                let (right_set, right_side, _) = self.line(right);
+                let (down_set, down_side, _) = self.line(down);
+                let (left_set, left_side, _) = self.line(left);
+                Some((
+                    set.crossing(
+                        up_side,
+                        up_set.border_type(),
+                        right_side,
+                        right_set.border_type(),
+                        down_side,
+                        down_set.border_type(),
+                        left_side,
+                        left_set.border_type(),
+                    ),
+                    self.crossing_style.unwrap_or(style),
+                ))
+            }
+            // straight lines and T-junctions.
+            (branch_up, Some(line), branch_down, Some(_)) => {
+                let (set, side, style) = self.line(line);
+                let symbol = match (branch_up, branch_down) {
+                    (Some(branch), None) => self.branch(side, Side::Top, branch),
+                    (None, Some(branch)) => self.branch(side, Side::Bottom, branch),
+                    _ => BorderSymbol::SideRegular,
+                };
+                Some((set.symbol(side, symbol), style))
+            }
+            (Some(line), branch_right, Some(_), branch_left) => {
+                let (set, side, style) = self.line(line);
+                let symbol = match (branch_right, branch_left) {
+                    (Some(branch), None) => self.branch(side, Side::Right, branch),
+                    (None, Some(branch)) => self.branch(side, Side::Left, branch),
+                    _ => BorderSymbol::SideRegular,
+                };
+                Some((set.symbol(side, symbol), style))
+            }
+            // corners, with the line types of both arms.
+            (Some(_), Some(line), None, None) | (Some(_), None, None, Some(line)) => {
+                let (set, _, style) = self.line(line);
+                Some((set.junction(Side::Bottom, self.corner(arms)), style))
             }
+            (None, Some(line), Some(_), None) | (None, None, Some(_), Some(line)) => {
+                let (set, _, style) = self.line(line);
+                Some((set.junction(Side::Top, self.corner(arms)), style))
+            }
+            // line ends.
+            (Some(line), None, None, None) => {
+                let (set, _, style) = self.line(line);
+                Some((set.line_end(Side::Bottom), style))
+            }
+            (None, Some(line), None, None) => {
+                let (set, _, style) = self.line(line);
+                Some((set.line_end(Side::Left), style))
+            }
+            (None, None, Some(line), None) => {
+                let (set, _, style) = self.line(line);
+                Some((set.line_end(Side::Top), style))
+            }
+            (None, None, None, Some(line)) => {
+                let (set, _, style) = self.line(line);
+                Some((set.line_end(Side::Right), style))
+            }
+        }
+    }
+
+    /// Junction for a corner. Both arms count as lines of the
+    /// border itself.
+    fn corner(&self, arms: [Option<GridLine>; 4]) -> Junction {
+        let [up, right, down, left] =
+            arms.map(|line| line.map(|v| Arm::own(self.line(v).0.border_type())));
+        Junction {
+            up,
+            right,
+            down,
+            left,
+        }
+    }
+
+    /// Junction for a branch leaving a line of the given side
+    /// in the given direction.
+    fn branch(&self, side: Side, direction: Side, branch: GridLine) -> BorderSymbol {
+        let (set, branch_side, _) = self.line(branch);
+        if direction == side.opposite() {
+            BorderSymbol::SideInward(branch_side, set.border_type())
+        } else {
+            BorderSymbol::SideOutward(branch_side, set.border_type())
         }
     }
 }
 
+/// Create a grid from the outlines of the given areas.
+fn create_nested_grid(areas: &[Rect]) -> BlockGrid {
+    let mut grid = BlockGrid::new();
+
+    // areas at the left or top edge leave no room for a border.
+    let areas = areas
+        .iter()
+        .filter(|v| v.x > 0 && v.y > 0)
+        .copied()
+        .collect::<Vec<_>>();
+
+    let Some(bounds) = areas.iter().copied().reduce(|a, b| a.union(b)) else {
+        return grid;
+    };
+
+    // relative to the outer border one cell before the bounds.
+    for area in areas {
+        grid.outlines.push(Rect::new(
+            area.x - bounds.x,
+            area.y - bounds.y,
+            area.width + 2,
+            area.height + 2,
+        ));
+    }
+
+    grid
+}
+
 /// Create a grid from the gaps left by the given areas.
 fn create_grid(areas: &[Rect]) -> BlockGrid {
     let mut grid = BlockGrid::new();
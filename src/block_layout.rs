@@ -0,0 +1,421 @@
+use crate::block_border::{
+    connect_overlaps, connect_symbols, degenerate_symbol, is_on_border, BlockBorder, BorderHit,
+    OverlapPolicy,
+};
+use crate::{BorderSymbol, Side};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Position, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{BorderType, Widget};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::collections::HashMap;
+
+///
+/// Renders the borders for a complete layout in one go.
+///
+/// Takes all the areas of the layout and their border types,
+/// and renders the connected borders for all of them.
+/// Each cell is rendered only once, if two borders share a
+/// cell the area that comes first wins.
+///
+/// This only connects areas which overlap at the edges. Use
+/// [Layout::spacing] with a value `Spacing::Overlap(1)` to create the
+/// areas for such a layout.
+///
+/// The areas are absolute positions, the area given when rendering
+/// is only used for clipping.
+///
+#[derive(Debug, Default, Clone)]
+pub struct ConnectedLayout {
+    border_style: Style,
+    focus: Option<usize>,
+    focus_style: Style,
+    overlap_policy: OverlapPolicy,
+    areas: Vec<Rect>,
+    borders: Vec<BorderType>,
+    // areas that share an edge coordinate with each area.
+    neighbours: Vec<Vec<usize>>,
+    // connected border for each area.
+    connected: OnceCell<Vec<BlockBorder>>,
+}
+
+impl ConnectedLayout {
+    ///
+    /// New layout.
+    ///
+    /// * areas - List of all involved areas.
+    /// * borders - Bordertype for each area.
+    ///
+    pub fn new(areas: &[Rect], borders: &[BorderType]) -> Self {
+        assert_eq!(areas.len(), borders.len());
+        let index = EdgeIndex::new(areas);
+        Self {
+            areas: areas.to_vec(),
+            borders: borders.to_vec(),
+            neighbours: (0..areas.len()).map(|n| index.neighbours(n)).collect(),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// New layout for areas that touch each other, but don't overlap.
+    ///
+    /// The areas are merged with [merge_touching], all the positions
+    /// returned by [area](ConnectedLayout::area) and
+    /// [inner](ConnectedLayout::inner) refer to the merged areas.
+    ///
+    pub fn from_touching(areas: &[Rect], borders: &[BorderType]) -> Self {
+        Self::new(&merge_touching(areas), borders)
+    }
+
+    ///
+    /// Border style for all borders.
+    ///
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    ///
+    /// Index of the focused area.
+    ///
+    /// The full outline of the focused area is rendered with the
+    /// [focus_style](ConnectedLayout::focus_style), including the
+    /// segments shared with other areas and the junctions.
+    /// An index outside the areas highlights nothing.
+    ///
+    pub fn focus(mut self, focus: Option<usize>) -> Self {
+        self.focus = focus;
+        self
+    }
+
+    ///
+    /// Style for the outline of the focused area.
+    ///
+    /// This is patched onto the border style.
+    ///
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    ///
+    /// Decides which border is shown on segments shared by two areas.
+    ///
+    /// Defaults to [OverlapPolicy::Own].
+    ///
+    pub fn overlap_policy(mut self, policy: OverlapPolicy) -> Self {
+        self.overlap_policy = policy;
+        self.connected = OnceCell::new();
+        self
+    }
+
+    ///
+    /// Number of areas.
+    ///
+    pub fn len(&self) -> usize {
+        self.areas.len()
+    }
+
+    ///
+    /// Layout has no areas.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+
+    ///
+    /// Area `n` including the border.
+    ///
+    pub fn area(&self, n: usize) -> Rect {
+        self.areas[n]
+    }
+
+    ///
+    /// Inner area of area `n` without the border.
+    ///
+    pub fn inner(&self, n: usize) -> Rect {
+        self.areas[n].inner(Margin::new(1, 1))
+    }
+
+    ///
+    /// What lies at the given position.
+    ///
+    /// Side and symbol are those of the area that renders the cell,
+    /// which is the first area with a border at the position.
+    ///
+    /// Returns None if the position is outside all areas.
+    ///
+    pub fn hit(&self, position: Position) -> Option<LayoutHit> {
+        let areas = (0..self.areas.len())
+            .filter(|n| is_on_border(self.areas[*n], position))
+            .collect::<Vec<_>>();
+
+        let Some(owner) = areas.first().copied() else {
+            return (0..self.areas.len())
+                .find(|n| self.areas[*n].contains(position))
+                .map(LayoutHit::Inside);
+        };
+
+        let block = &self.connected()[owner];
+        let Some(BorderHit::Border(side, symbol)) = block.hit(self.areas[owner], position) else {
+            return None;
+        };
+
+        // everything but the plain line and the own corners
+        // connects to another border.
+        let connected = !matches!(
+            symbol,
+            BorderSymbol::SideRegular
+                | BorderSymbol::SideOverlap(_, _)
+                | BorderSymbol::StartCornerRegular
+                | BorderSymbol::EndCornerRegular
+        );
+        if connected {
+            Some(LayoutHit::Junction {
+                areas,
+                side,
+                symbol,
+            })
+        } else {
+            Some(LayoutHit::Edge {
+                areas,
+                side,
+                symbol,
+            })
+        }
+    }
+
+    // Connected borders of all areas. Built on first use.
+    fn connected(&self) -> &[BlockBorder] {
+        self.connected.get_or_init(|| {
+            // the symbols of each area are needed for the overlaps of
+            // the neighbours too.
+            let symbols = (0..self.areas.len())
+                .map(|n| {
+                    connect_symbols(
+                        &self.areas,
+                        &self.borders,
+                        n,
+                        self.neighbours[n].iter().copied(),
+                        self.overlap_policy,
+                    )
+                })
+                .collect::<Vec<_>>();
+            (0..self.areas.len())
+                .map(|n| {
+                    let mut block = symbols[n].clone();
+                    connect_overlaps(
+                        &mut block,
+                        &self.areas,
+                        &self.borders,
+                        n,
+                        &self.neighbours[n],
+                        self.overlap_policy,
+                        &|i| Cow::Borrowed(&symbols[i]),
+                    );
+                    block
+                })
+                .collect()
+        })
+    }
+}
+
+///
+/// Result of [ConnectedLayout::hit].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutHit {
+    /// On a border segment. Lists all areas that share the segment.
+    Edge {
+        /// Indexes of the areas.
+        areas: Vec<usize>,
+        /// Side of the first area.
+        side: Side,
+        /// Symbol of the first area.
+        symbol: BorderSymbol,
+    },
+    /// On a junction where several borders meet. Lists all areas
+    /// that have a border at the junction.
+    Junction {
+        /// Indexes of the areas.
+        areas: Vec<usize>,
+        /// Side of the first area.
+        side: Side,
+        /// Symbol of the first area.
+        symbol: BorderSymbol,
+    },
+    /// Inside the area with the given index.
+    Inside(usize),
+}
+
+impl Widget for ConnectedLayout {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &ConnectedLayout {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let clip = area.intersection(buf.area);
+        if clip.is_empty() {
+            return;
+        }
+
+        let mut rendered = vec![false; clip.area() as usize];
+
+        for (n, connected) in self.connected().iter().enumerate() {
+            let mut block = Cow::Borrowed(connected);
+            if let Some(outline) = self.focus.and_then(|v| self.areas.get(v)) {
+                block
+                    .to_mut()
+                    .set_outline_style(self.areas[n], *outline, self.focus_style)
+                    .expect("border built for the area");
+            }
+            let symbols = block.symbol_set.as_ref();
+            let Some(prefab) = block.prefab.as_ref() else {
+                continue;
+            };
+
+            let area = self.areas[n];
+            prefab.for_each_cell(area, symbols, |pos, _, glyph, style| {
+                if !clip.contains(pos) {
+                    return;
+                }
+                let idx =
+                    (pos.y - clip.y) as usize * clip.width as usize + (pos.x - clip.x) as usize;
+                if rendered[idx] {
+                    return;
+                }
+                rendered[idx] = true;
+
+                let cell = &mut buf[pos];
+                cell.set_style(self.border_style.patch(style));
+                if area.width < 2 || area.height < 2 {
+                    cell.set_symbol(degenerate_symbol(symbols, area, pos).1);
+                } else {
+                    cell.set_symbol(glyph);
+                }
+            });
+        }
+    }
+}
+
+///
+/// Merges areas that touch each other.
+///
+/// Areas created without `Spacing::Overlap(1)` lie side by side,
+/// and their borders would be rendered as two parallel lines.
+/// This extends an area by one cell to the right if there is
+/// another area directly adjacent to the right, and by one cell
+/// down if there is another area directly below. The two borders
+/// then share one line and can be connected with
+/// [BlockBorder::from_layout](crate::block_border::BlockBorder::from_layout).
+///
+/// Areas only count as adjacent if they share at least one
+/// row/column along the touching edge.
+///
+pub fn merge_touching(areas: &[Rect]) -> Vec<Rect> {
+    let mut left: HashMap<u16, Vec<usize>> = HashMap::new();
+    let mut top: HashMap<u16, Vec<usize>> = HashMap::new();
+    for (i, area) in areas.iter().enumerate() {
+        if !area.is_empty() {
+            left.entry(area.x).or_default().push(i);
+            top.entry(area.y).or_default().push(i);
+        }
+    }
+
+    areas
+        .iter()
+        .map(|area| {
+            if area.is_empty() {
+                return *area;
+            }
+            let mut merged = *area;
+            if let Some(right) = left.get(&area.right()) {
+                if right
+                    .iter()
+                    .any(|i| areas[*i].top() < area.bottom() && area.top() < areas[*i].bottom())
+                {
+                    merged.width += 1;
+                }
+            }
+            if let Some(below) = top.get(&area.bottom()) {
+                if below
+                    .iter()
+                    .any(|i| areas[*i].left() < area.right() && area.left() < areas[*i].right())
+                {
+                    merged.height += 1;
+                }
+            }
+            merged
+        })
+        .collect()
+}
+
+/// Index of the areas by the coordinates of their edges.
+/// Used to find the neighbours of an area without testing
+/// every other area.
+struct EdgeIndex<'a> {
+    areas: &'a [Rect],
+    top: HashMap<u16, Vec<usize>>,
+    bottom: HashMap<u16, Vec<usize>>,
+    left: HashMap<u16, Vec<usize>>,
+    right: HashMap<u16, Vec<usize>>,
+}
+
+impl<'a> EdgeIndex<'a> {
+    fn new(areas: &'a [Rect]) -> Self {
+        let mut index = Self {
+            areas,
+            top: Default::default(),
+            bottom: Default::default(),
+            left: Default::default(),
+            right: Default::default(),
+        };
+        for (i, area) in areas.iter().enumerate() {
+            index.top.entry(area.y).or_default().push(i);
+            index
+                .bottom
+                .entry(area.y + area.height.saturating_sub(1))
+                .or_default()
+                .push(i);
+            index.left.entry(area.x).or_default().push(i);
+            index
+                .right
+                .entry(area.x + area.width.saturating_sub(1))
+                .or_default()
+                .push(i);
+        }
+        index
+    }
+
+    /// All areas that share an edge coordinate with area n.
+    fn neighbours(&self, n: usize) -> Vec<usize> {
+        let area = self.areas[n];
+        let x2 = area.x + area.width.saturating_sub(1);
+        let y2 = area.y + area.height.saturating_sub(1);
+
+        let mut neighbours = Vec::new();
+        for (map, key) in [
+            (&self.bottom, area.y),
+            (&self.top, y2),
+            (&self.right, area.x),
+            (&self.left, x2),
+        ] {
+            if let Some(found) = map.get(&key) {
+                neighbours.extend_from_slice(found);
+            }
+        }
+        neighbours.sort_unstable();
+        neighbours.dedup();
+        neighbours
+    }
+}
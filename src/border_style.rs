@@ -0,0 +1,252 @@
+use crate::Side;
+use dyn_clone::DynClone;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Color, Style, Stylize};
+
+///
+/// Provides the style for each cell of a border.
+///
+/// This is called for every cell of the border when rendering.
+/// The returned style is patched onto the border style.
+///
+pub trait BorderStyleProvider: DynClone {
+    ///
+    /// Style for one cell of the border.
+    ///
+    /// area: The area of the border.
+    /// position: Position of the cell.
+    /// side: Side of the cell. Corners belong to the top and bottom side.
+    /// index: Position along the perimeter. Starts with 0 at the top left
+    ///        corner and goes around clockwise.
+    /// len: Length of the perimeter.
+    ///
+    fn style(&self, area: Rect, position: Position, side: Side, index: usize, len: usize) -> Style;
+}
+
+///
+/// Position of the cell along the perimeter of the area.
+///
+/// Starts with 0 at the top left corner and goes around clockwise.
+///
+pub fn perimeter_index(area: Rect, position: Position) -> usize {
+    let x = position.x.saturating_sub(area.x) as usize;
+    let y = position.y.saturating_sub(area.y) as usize;
+    let w = area.width.saturating_sub(1) as usize;
+    let h = area.height.saturating_sub(1) as usize;
+
+    if y == 0 {
+        x
+    } else if x == w {
+        w + y
+    } else if y == h {
+        w + h + (w - x)
+    } else {
+        2 * w + h + (h - y)
+    }
+}
+
+///
+/// Length of the perimeter of the area.
+///
+pub fn perimeter_len(area: Rect) -> usize {
+    let w = area.width.saturating_sub(1) as usize;
+    let h = area.height.saturating_sub(1) as usize;
+    match (w, h) {
+        (0, h) => h + 1,
+        (w, 0) => w + 1,
+        (w, h) => 2 * w + 2 * h,
+    }
+}
+
+/// Direction of a [LinearGradient].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GradientDirection {
+    /// From left to right.
+    #[default]
+    Horizontal,
+    /// From top to bottom.
+    Vertical,
+    /// Clockwise around the border starting at the top left corner.
+    Perimeter,
+}
+
+///
+/// Colors the border with a gradient between two colors.
+///
+/// Only [Color::Rgb] can be interpolated, for all other colors
+/// the gradient switches from start to end halfway.
+///
+/// The gradient is applied to the foreground color.
+///
+#[derive(Debug, Clone)]
+pub struct LinearGradient {
+    start: Color,
+    end: Color,
+    direction: GradientDirection,
+}
+
+impl LinearGradient {
+    ///
+    /// New gradient.
+    ///
+    pub fn new(start: Color, end: Color) -> Self {
+        Self {
+            start,
+            end,
+            direction: Default::default(),
+        }
+    }
+
+    ///
+    /// Direction of the gradient.
+    ///
+    pub fn direction(mut self, direction: GradientDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Color at t, where t goes from 0 to max.
+    fn color(&self, t: usize, max: usize) -> Color {
+        if max == 0 {
+            return self.start;
+        }
+        match (self.start, self.end) {
+            (Color::Rgb(r0, g0, b0), Color::Rgb(r1, g1, b1)) => {
+                let mix = |c0: u8, c1: u8| -> u8 {
+                    (c0 as isize + (c1 as isize - c0 as isize) * t as isize / max as isize) as u8
+                };
+                Color::Rgb(mix(r0, r1), mix(g0, g1), mix(b0, b1))
+            }
+            _ => {
+                if t * 2 < max {
+                    self.start
+                } else {
+                    self.end
+                }
+            }
+        }
+    }
+}
+
+impl BorderStyleProvider for LinearGradient {
+    fn style(
+        &self,
+        area: Rect,
+        position: Position,
+        _side: Side,
+        index: usize,
+        len: usize,
+    ) -> Style {
+        let color = match self.direction {
+            GradientDirection::Horizontal => self.color(
+                position.x.saturating_sub(area.x) as usize,
+                area.width.saturating_sub(1) as usize,
+            ),
+            GradientDirection::Vertical => self.color(
+                position.y.saturating_sub(area.y) as usize,
+                area.height.saturating_sub(1) as usize,
+            ),
+            GradientDirection::Perimeter => self.color(index, len),
+        };
+        Style::new().fg(color)
+    }
+}
+
+///
+/// Marching ants.
+///
+/// Alternates between two styles in dashes along the border.
+/// Increase the frame counter to move the dashes clockwise
+/// around the border.
+///
+#[derive(Debug, Clone)]
+pub struct Marquee {
+    on_style: Style,
+    off_style: Style,
+    dash: usize,
+    gap: usize,
+    frame: usize,
+}
+
+impl Default for Marquee {
+    fn default() -> Self {
+        Self {
+            on_style: Style::new().reversed(),
+            off_style: Default::default(),
+            dash: 2,
+            gap: 2,
+            frame: 0,
+        }
+    }
+}
+
+impl Marquee {
+    ///
+    /// New marquee.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Style for the dashes.
+    ///
+    pub fn on_style(mut self, style: Style) -> Self {
+        self.on_style = style;
+        self
+    }
+
+    ///
+    /// Style for the gaps between the dashes.
+    ///
+    pub fn off_style(mut self, style: Style) -> Self {
+        self.off_style = style;
+        self
+    }
+
+    ///
+    /// Length of a dash.
+    ///
+    pub fn dash(mut self, len: usize) -> Self {
+        self.dash = len;
+        self
+    }
+
+    ///
+    /// Length of a gap between the dashes.
+    ///
+    pub fn gap(mut self, len: usize) -> Self {
+        self.gap = len;
+        self
+    }
+
+    ///
+    /// Frame counter.
+    ///
+    pub fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+}
+
+impl BorderStyleProvider for Marquee {
+    fn style(
+        &self,
+        _area: Rect,
+        _position: Position,
+        _side: Side,
+        index: usize,
+        len: usize,
+    ) -> Style {
+        let period = self.dash + self.gap;
+        if period == 0 || len == 0 {
+            return self.off_style;
+        }
+        let shifted = (index + len - self.frame % len) % len;
+        if shifted % period < self.dash {
+            self.on_style
+        } else {
+            self.off_style
+        }
+    }
+}
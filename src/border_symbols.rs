@@ -263,6 +263,13 @@ macro_rules! plain {
 }
 
 /// Plain border symbol set.
+///
+/// Connections to double borders use the mixed single/double glyphs.
+/// Unicode only has those where both arms along one axis have the same
+/// weight. For all other combinations the mixed axis takes the weight
+/// that is in the minority among all arms of the glyph, so the
+/// connection stays visible. On a tie the double weight wins.
+/// E.g. a start corner angled to a double border becomes `╟`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PlainSymbolSet;
 
@@ -280,14 +287,21 @@ impl BorderSymbolSet for PlainSymbolSet {
             Top => match symbol {
                 BorderSymbol::StartCornerRegular => "┌",
                 BorderSymbol::StartCornerAngled(_, Thick) => "┞",
+                BorderSymbol::StartCornerAngled(_, Double) => "╟",
                 BorderSymbol::StartCornerAngled(_, _) => "├",
                 BorderSymbol::StartCornerProlonged(_, Thick) => "┭",
+                BorderSymbol::StartCornerProlonged(_, Double) => "╤",
                 BorderSymbol::StartCornerProlonged(_, _) => "┬",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, Thick) => "╃",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, _) => "╀",
                 BorderSymbol::StartCornerCrossed(_, _, _, Thick) => "┽",
+                BorderSymbol::StartCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::StartCornerCrossed(_, Double, _, _) => "╫",
+                BorderSymbol::StartCornerCrossed(_, _, _, Double) => "╪",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "┼",
                 BorderSymbol::SideRegular => "─",
+                BorderSymbol::SideOverlap(_, Double) => "═",
+                BorderSymbol::SideOverlap(_, Thick) => "━",
                 BorderSymbol::SideOverlap(_, _) => "─",
                 BorderSymbol::SideOutward(_, Thick) => "┸",
                 BorderSymbol::SideOutward(_, Double) => "╨",
@@ -298,29 +312,42 @@ impl BorderSymbolSet for PlainSymbolSet {
                 BorderSymbol::SideCrossed(_, Thick, _, Thick) => "╂",
                 BorderSymbol::SideCrossed(_, Thick, _, _) => "╀",
                 BorderSymbol::SideCrossed(_, _, _, Thick) => "╁",
-                BorderSymbol::SideCrossed(_, Double, _, Double) => "╫",
+                BorderSymbol::SideCrossed(_, Double, _, _)
+                | BorderSymbol::SideCrossed(_, _, _, Double) => "╫",
                 BorderSymbol::SideCrossed(_, _, _, _) => "┼",
                 BorderSymbol::EndCornerRegular => "┐",
                 BorderSymbol::EndCornerAngled(_, Thick) => "┦",
+                BorderSymbol::EndCornerAngled(_, Double) => "╢",
                 BorderSymbol::EndCornerAngled(_, _) => "┤",
                 BorderSymbol::EndCornerProlonged(_, Thick) => "┮",
+                BorderSymbol::EndCornerProlonged(_, Double) => "╤",
                 BorderSymbol::EndCornerProlonged(_, _) => "┬",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, Thick) => "╄",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, _) => "╀",
                 BorderSymbol::EndCornerCrossed(_, _, _, Thick) => "┾",
+                BorderSymbol::EndCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::EndCornerCrossed(_, Double, _, _) => "╫",
+                BorderSymbol::EndCornerCrossed(_, _, _, Double) => "╪",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "┼",
             },
             Bottom => match symbol {
                 BorderSymbol::StartCornerRegular => "└",
                 BorderSymbol::StartCornerAngled(_, Thick) => "┟",
+                BorderSymbol::StartCornerAngled(_, Double) => "╟",
                 BorderSymbol::StartCornerAngled(_, _) => "├",
                 BorderSymbol::StartCornerProlonged(_, Thick) => "┵",
+                BorderSymbol::StartCornerProlonged(_, Double) => "╧",
                 BorderSymbol::StartCornerProlonged(_, _) => "┴",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, Thick) => "╅",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, _) => "╁",
                 BorderSymbol::StartCornerCrossed(_, _, _, Thick) => "┽",
+                BorderSymbol::StartCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::StartCornerCrossed(_, Double, _, _) => "╫",
+                BorderSymbol::StartCornerCrossed(_, _, _, Double) => "╪",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "┼",
                 BorderSymbol::SideRegular => "─",
+                BorderSymbol::SideOverlap(_, Double) => "═",
+                BorderSymbol::SideOverlap(_, Thick) => "━",
                 BorderSymbol::SideOverlap(_, _) => "─",
                 BorderSymbol::SideOutward(_, Thick) => "┰",
                 BorderSymbol::SideOutward(_, Double) => "╥",
@@ -331,29 +358,42 @@ impl BorderSymbolSet for PlainSymbolSet {
                 BorderSymbol::SideCrossed(_, Thick, _, Thick) => "╂",
                 BorderSymbol::SideCrossed(_, Thick, _, _) => "╁",
                 BorderSymbol::SideCrossed(_, _, _, Thick) => "╀",
-                BorderSymbol::SideCrossed(_, Double, _, Double) => "╫",
+                BorderSymbol::SideCrossed(_, Double, _, _)
+                | BorderSymbol::SideCrossed(_, _, _, Double) => "╫",
                 BorderSymbol::SideCrossed(_, _, _, _) => "┼",
                 BorderSymbol::EndCornerRegular => "┘",
                 BorderSymbol::EndCornerAngled(_, Thick) => "┧",
+                BorderSymbol::EndCornerAngled(_, Double) => "╢",
                 BorderSymbol::EndCornerAngled(_, _) => "┤",
                 BorderSymbol::EndCornerProlonged(_, Thick) => "┶",
+                BorderSymbol::EndCornerProlonged(_, Double) => "╧",
                 BorderSymbol::EndCornerProlonged(_, _) => "┴",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, Thick) => "╆",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, _) => "╁",
                 BorderSymbol::EndCornerCrossed(_, _, _, Thick) => "┾",
+                BorderSymbol::EndCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::EndCornerCrossed(_, Double, _, _) => "╫",
+                BorderSymbol::EndCornerCrossed(_, _, _, Double) => "╪",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "┼",
             },
             Right => match symbol {
                 BorderSymbol::StartCornerRegular => "┐",
                 BorderSymbol::StartCornerAngled(_, Thick) => "┮",
+                BorderSymbol::StartCornerAngled(_, Double) => "╤",
                 BorderSymbol::StartCornerAngled(_, _) => "┬",
                 BorderSymbol::StartCornerProlonged(_, Thick) => "┦",
+                BorderSymbol::StartCornerProlonged(_, Double) => "╢",
                 BorderSymbol::StartCornerProlonged(_, _) => "┤",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, Thick) => "╄",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, _) => "┾",
                 BorderSymbol::StartCornerCrossed(_, _, _, Thick) => "╀",
+                BorderSymbol::StartCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::StartCornerCrossed(_, Double, _, _) => "╪",
+                BorderSymbol::StartCornerCrossed(_, _, _, Double) => "╫",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "┼",
                 BorderSymbol::SideRegular => "│",
+                BorderSymbol::SideOverlap(_, Double) => "║",
+                BorderSymbol::SideOverlap(_, Thick) => "┃",
                 BorderSymbol::SideOverlap(_, _) => "│",
                 BorderSymbol::SideOutward(_, Thick) => "┝",
                 BorderSymbol::SideOutward(_, Double) => "╞",
@@ -364,29 +404,42 @@ impl BorderSymbolSet for PlainSymbolSet {
                 BorderSymbol::SideCrossed(_, Thick, _, Thick) => "┿",
                 BorderSymbol::SideCrossed(_, Thick, _, _) => "┾",
                 BorderSymbol::SideCrossed(_, _, _, Thick) => "┽",
-                BorderSymbol::SideCrossed(_, Double, _, Double) => "╪",
+                BorderSymbol::SideCrossed(_, Double, _, _)
+                | BorderSymbol::SideCrossed(_, _, _, Double) => "╪",
                 BorderSymbol::SideCrossed(_, _, _, _) => "┼",
                 BorderSymbol::EndCornerRegular => "┘",
                 BorderSymbol::EndCornerAngled(_, Thick) => "┶",
+                BorderSymbol::EndCornerAngled(_, Double) => "╧",
                 BorderSymbol::EndCornerAngled(_, _) => "┴",
                 BorderSymbol::EndCornerProlonged(_, Thick) => "┧",
+                BorderSymbol::EndCornerProlonged(_, Double) => "╢",
                 BorderSymbol::EndCornerProlonged(_, _) => "┤",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, Thick) => "╆",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, _) => "┾",
                 BorderSymbol::EndCornerCrossed(_, _, _, Thick) => "╁",
+                BorderSymbol::EndCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::EndCornerCrossed(_, Double, _, _) => "╪",
+                BorderSymbol::EndCornerCrossed(_, _, _, Double) => "╫",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "┼",
             },
             Left => match symbol {
                 BorderSymbol::StartCornerRegular => "┌",
                 BorderSymbol::StartCornerAngled(_, Thick) => "┭",
+                BorderSymbol::StartCornerAngled(_, Double) => "╤",
                 BorderSymbol::StartCornerAngled(_, _) => "┬",
                 BorderSymbol::StartCornerProlonged(_, Thick) => "┞",
+                BorderSymbol::StartCornerProlonged(_, Double) => "╟",
                 BorderSymbol::StartCornerProlonged(_, _) => "├",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, Thick) => "╃",
                 BorderSymbol::StartCornerCrossed(_, Thick, _, _) => "┽",
                 BorderSymbol::StartCornerCrossed(_, _, _, Thick) => "╀",
+                BorderSymbol::StartCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::StartCornerCrossed(_, Double, _, _) => "╪",
+                BorderSymbol::StartCornerCrossed(_, _, _, Double) => "╫",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "┼",
                 BorderSymbol::SideRegular => "│",
+                BorderSymbol::SideOverlap(_, Double) => "║",
+                BorderSymbol::SideOverlap(_, Thick) => "┃",
                 BorderSymbol::SideOverlap(_, _) => "│",
                 BorderSymbol::SideOutward(_, Thick) => "┥",
                 BorderSymbol::SideOutward(_, Double) => "╡",
@@ -397,16 +450,22 @@ impl BorderSymbolSet for PlainSymbolSet {
                 BorderSymbol::SideCrossed(_, Thick, _, Thick) => "┿",
                 BorderSymbol::SideCrossed(_, Thick, _, _) => "┽",
                 BorderSymbol::SideCrossed(_, _, _, Thick) => "┾",
-                BorderSymbol::SideCrossed(_, Double, _, Double) => "╪",
+                BorderSymbol::SideCrossed(_, Double, _, _)
+                | BorderSymbol::SideCrossed(_, _, _, Double) => "╪",
                 BorderSymbol::SideCrossed(_, _, _, _) => "┼",
                 BorderSymbol::EndCornerRegular => "└",
                 BorderSymbol::EndCornerAngled(_, Thick) => "┵",
+                BorderSymbol::EndCornerAngled(_, Double) => "╧",
                 BorderSymbol::EndCornerAngled(_, _) => "┴",
                 BorderSymbol::EndCornerProlonged(_, Thick) => "┟",
+                BorderSymbol::EndCornerProlonged(_, Double) => "╟",
                 BorderSymbol::EndCornerProlonged(_, _) => "├",
-                BorderSymbol::EndCornerCrossed(_, Thick, _, Thick) => "╆",
+                BorderSymbol::EndCornerCrossed(_, Thick, _, Thick) => "╅",
                 BorderSymbol::EndCornerCrossed(_, Thick, _, _) => "┽",
                 BorderSymbol::EndCornerCrossed(_, _, _, Thick) => "╁",
+                BorderSymbol::EndCornerCrossed(_, Double, _, Double) => "╬",
+                BorderSymbol::EndCornerCrossed(_, Double, _, _) => "╪",
+                BorderSymbol::EndCornerCrossed(_, _, _, Double) => "╫",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "┼",
             },
         }
@@ -449,6 +508,15 @@ impl BorderSymbolSet for PlainSymbolSet {
             (_, _, _, _) => "┼",
         }
     }
+
+    fn line_end(&self, side: Side) -> &'static str {
+        match side {
+            Side::Top => "╷",
+            Side::Right => "╴",
+            Side::Bottom => "╵",
+            Side::Left => "╶",
+        }
+    }
 }
 
 /// Rounded border symbol set.
@@ -510,9 +578,18 @@ impl BorderSymbolSet for RoundedSymbolSet {
             left,
         )
     }
+
+    fn line_end(&self, side: Side) -> &'static str {
+        PlainSymbolSet.line_end(side)
+    }
 }
 
 /// Double border symbol set.
+///
+/// Connections to plain borders use the mixed single/double glyphs.
+/// Where Unicode has no fitting glyph the same fallback as for
+/// [PlainSymbolSet] is used. E.g. a start corner angled to a plain
+/// border becomes `╞`. Thick borders are treated as double.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DoubleSymbolSet;
 
@@ -528,74 +605,126 @@ impl BorderSymbolSet for DoubleSymbolSet {
         match side {
             Top => match symbol {
                 BorderSymbol::StartCornerRegular => "╔",
+                BorderSymbol::StartCornerAngled(_, plain!()) => "╞",
                 BorderSymbol::StartCornerAngled(_, _) => "╠",
+                BorderSymbol::StartCornerProlonged(_, plain!()) => "╥",
                 BorderSymbol::StartCornerProlonged(_, _) => "╦",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╪",
+                BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╫",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╬",
                 BorderSymbol::SideRegular => "═",
+                BorderSymbol::SideOverlap(_, plain!()) => "─",
+                BorderSymbol::SideOverlap(_, BorderType::Thick) => "━",
                 BorderSymbol::SideOverlap(_, _) => "═",
                 BorderSymbol::SideOutward(_, plain!()) => "╧",
                 BorderSymbol::SideOutward(_, _) => "╩",
                 BorderSymbol::SideInward(_, plain!()) => "╤",
                 BorderSymbol::SideInward(_, _) => "╦",
-                BorderSymbol::SideCrossed(_, plain!(), _, plain!()) => "╪",
+                BorderSymbol::SideCrossed(_, plain!(), _, _)
+                | BorderSymbol::SideCrossed(_, _, _, plain!()) => "╪",
                 BorderSymbol::SideCrossed(_, _, _, _) => "╬",
                 BorderSymbol::EndCornerRegular => "╗",
+                BorderSymbol::EndCornerAngled(_, plain!()) => "╡",
                 BorderSymbol::EndCornerAngled(_, _) => "╣",
+                BorderSymbol::EndCornerProlonged(_, plain!()) => "╥",
                 BorderSymbol::EndCornerProlonged(_, _) => "╦",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, _) => "╪",
+                BorderSymbol::EndCornerCrossed(_, _, _, plain!()) => "╫",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "╬",
             },
             Bottom => match symbol {
                 BorderSymbol::StartCornerRegular => "╚",
+                BorderSymbol::StartCornerAngled(_, plain!()) => "╞",
                 BorderSymbol::StartCornerAngled(_, _) => "╠",
+                BorderSymbol::StartCornerProlonged(_, plain!()) => "╨",
                 BorderSymbol::StartCornerProlonged(_, _) => "╩",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╪",
+                BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╫",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╬",
                 BorderSymbol::SideRegular => "═",
+                BorderSymbol::SideOverlap(_, plain!()) => "─",
+                BorderSymbol::SideOverlap(_, BorderType::Thick) => "━",
                 BorderSymbol::SideOverlap(_, _) => "═",
                 BorderSymbol::SideOutward(_, plain!()) => "╤",
                 BorderSymbol::SideOutward(_, _) => "╦",
                 BorderSymbol::SideInward(_, plain!()) => "╧",
                 BorderSymbol::SideInward(_, _) => "╩",
-                BorderSymbol::SideCrossed(_, plain!(), _, plain!()) => "╪",
+                BorderSymbol::SideCrossed(_, plain!(), _, _)
+                | BorderSymbol::SideCrossed(_, _, _, plain!()) => "╪",
                 BorderSymbol::SideCrossed(_, _, _, _) => "╬",
                 BorderSymbol::EndCornerRegular => "╝",
+                BorderSymbol::EndCornerAngled(_, plain!()) => "╡",
                 BorderSymbol::EndCornerAngled(_, _) => "╣",
+                BorderSymbol::EndCornerProlonged(_, plain!()) => "╨",
                 BorderSymbol::EndCornerProlonged(_, _) => "╩",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, _) => "╪",
+                BorderSymbol::EndCornerCrossed(_, _, _, plain!()) => "╫",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "╬",
             },
             Right => match symbol {
                 BorderSymbol::StartCornerRegular => "╗",
+                BorderSymbol::StartCornerAngled(_, plain!()) => "╥",
                 BorderSymbol::StartCornerAngled(_, _) => "╦",
+                BorderSymbol::StartCornerProlonged(_, plain!()) => "╡",
                 BorderSymbol::StartCornerProlonged(_, _) => "╣",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╫",
+                BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╪",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╬",
                 BorderSymbol::SideRegular => "║",
+                BorderSymbol::SideOverlap(_, plain!()) => "│",
+                BorderSymbol::SideOverlap(_, BorderType::Thick) => "┃",
                 BorderSymbol::SideOverlap(_, _) => "║",
                 BorderSymbol::SideOutward(_, plain!()) => "╟",
                 BorderSymbol::SideOutward(_, _) => "╠",
                 BorderSymbol::SideInward(_, plain!()) => "╢",
                 BorderSymbol::SideInward(_, _) => "╣",
-                BorderSymbol::SideCrossed(_, plain!(), _, plain!()) => "╫",
+                BorderSymbol::SideCrossed(_, plain!(), _, _)
+                | BorderSymbol::SideCrossed(_, _, _, plain!()) => "╫",
                 BorderSymbol::SideCrossed(_, _, _, _) => "╬",
                 BorderSymbol::EndCornerRegular => "╝",
+                BorderSymbol::EndCornerAngled(_, plain!()) => "╨",
                 BorderSymbol::EndCornerAngled(_, _) => "╩",
+                BorderSymbol::EndCornerProlonged(_, plain!()) => "╡",
                 BorderSymbol::EndCornerProlonged(_, _) => "╣",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, _) => "╫",
+                BorderSymbol::EndCornerCrossed(_, _, _, plain!()) => "╪",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "╬",
             },
             Left => match symbol {
                 BorderSymbol::StartCornerRegular => "╔",
+                BorderSymbol::StartCornerAngled(_, plain!()) => "╥",
                 BorderSymbol::StartCornerAngled(_, _) => "╦",
+                BorderSymbol::StartCornerProlonged(_, plain!()) => "╞",
                 BorderSymbol::StartCornerProlonged(_, _) => "╠",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╫",
+                BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╪",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╬",
                 BorderSymbol::SideRegular => "║",
+                BorderSymbol::SideOverlap(_, plain!()) => "│",
+                BorderSymbol::SideOverlap(_, BorderType::Thick) => "┃",
                 BorderSymbol::SideOverlap(_, _) => "║",
                 BorderSymbol::SideOutward(_, plain!()) => "╢",
                 BorderSymbol::SideOutward(_, _) => "╣",
                 BorderSymbol::SideInward(_, plain!()) => "╟",
                 BorderSymbol::SideInward(_, _) => "╠",
-                BorderSymbol::SideCrossed(_, plain!(), _, plain!()) => "╫",
+                BorderSymbol::SideCrossed(_, plain!(), _, _)
+                | BorderSymbol::SideCrossed(_, _, _, plain!()) => "╫",
                 BorderSymbol::SideCrossed(_, _, _, _) => "╬",
                 BorderSymbol::EndCornerRegular => "╚",
+                BorderSymbol::EndCornerAngled(_, plain!()) => "╨",
                 BorderSymbol::EndCornerAngled(_, _) => "╩",
+                BorderSymbol::EndCornerProlonged(_, plain!()) => "╞",
                 BorderSymbol::EndCornerProlonged(_, _) => "╠",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, plain!()) => "╬",
+                BorderSymbol::EndCornerCrossed(_, plain!(), _, _) => "╫",
+                BorderSymbol::EndCornerCrossed(_, _, _, plain!()) => "╪",
                 BorderSymbol::EndCornerCrossed(_, _, _, _) => "╬",
             },
         }
@@ -647,6 +776,8 @@ impl BorderSymbolSet for ThickSymbolSet {
                 BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╊",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╋",
                 BorderSymbol::SideRegular => "━",
+                BorderSymbol::SideOverlap(_, plain!()) => "─",
+                BorderSymbol::SideOverlap(_, Double) => "═",
                 BorderSymbol::SideOverlap(_, _) => "━",
                 BorderSymbol::SideOutward(_, plain!()) => "┷",
                 BorderSymbol::SideOutward(_, _) => "┻",
@@ -671,12 +802,14 @@ impl BorderSymbolSet for ThickSymbolSet {
                 BorderSymbol::StartCornerAngled(_, plain!()) => "┡",
                 BorderSymbol::StartCornerAngled(_, _) => "┣",
                 BorderSymbol::StartCornerProlonged(_, plain!()) => "┺",
-                BorderSymbol::StartCornerProlonged(_, _) => "┹",
+                BorderSymbol::StartCornerProlonged(_, _) => "┻",
                 BorderSymbol::StartCornerCrossed(_, plain!(), _, plain!()) => "╄",
                 BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╇",
                 BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╊",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╋",
                 BorderSymbol::SideRegular => "━",
+                BorderSymbol::SideOverlap(_, plain!()) => "─",
+                BorderSymbol::SideOverlap(_, Double) => "═",
                 BorderSymbol::SideOverlap(_, _) => "━",
                 BorderSymbol::SideOutward(_, plain!()) => "┯",
                 BorderSymbol::SideOutward(_, _) => "┳",
@@ -703,10 +836,12 @@ impl BorderSymbolSet for ThickSymbolSet {
                 BorderSymbol::StartCornerProlonged(_, plain!()) => "┪",
                 BorderSymbol::StartCornerProlonged(_, _) => "┫",
                 BorderSymbol::StartCornerCrossed(_, plain!(), _, plain!()) => "╅",
-                BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╈",
-                BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╉",
+                BorderSymbol::StartCornerCrossed(_, plain!(), _, _) => "╉",
+                BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╈",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╋",
                 BorderSymbol::SideRegular => "┃",
+                BorderSymbol::SideOverlap(_, plain!()) => "│",
+                BorderSymbol::SideOverlap(_, Double) => "║",
                 BorderSymbol::SideOverlap(_, _) => "┃",
                 BorderSymbol::SideOutward(_, plain!()) => "┠",
                 BorderSymbol::SideOutward(_, _) => "┣",
@@ -737,6 +872,8 @@ impl BorderSymbolSet for ThickSymbolSet {
                 BorderSymbol::StartCornerCrossed(_, _, _, plain!()) => "╈",
                 BorderSymbol::StartCornerCrossed(_, _, _, _) => "╋",
                 BorderSymbol::SideRegular => "┃",
+                BorderSymbol::SideOverlap(_, plain!()) => "│",
+                BorderSymbol::SideOverlap(_, Double) => "║",
                 BorderSymbol::SideOverlap(_, _) => "┃",
                 BorderSymbol::SideOutward(_, plain!()) => "┨",
                 BorderSymbol::SideOutward(_, _) => "┫",
@@ -750,7 +887,7 @@ impl BorderSymbolSet for ThickSymbolSet {
                 BorderSymbol::EndCornerAngled(_, plain!()) => "┺",
                 BorderSymbol::EndCornerAngled(_, _) => "┻",
                 BorderSymbol::EndCornerProlonged(_, plain!()) => "┡",
-                BorderSymbol::EndCornerProlonged(_, _) => "┫",
+                BorderSymbol::EndCornerProlonged(_, _) => "┣",
                 BorderSymbol::EndCornerCrossed(_, plain!(), _, plain!()) => "╄",
                 BorderSymbol::EndCornerCrossed(_, plain!(), _, _) => "╊",
                 BorderSymbol::EndCornerCrossed(_, _, _, plain!()) => "╇",
@@ -789,6 +926,19 @@ impl BorderSymbolSet for ThickSymbolSet {
             (_, _, _, _) => "╋",
         }
     }
+
+    fn line_end(&self, side: Side) -> &'static str {
+        match side {
+            Side::Top => "╻",
+            Side::Right => "╸",
+            Side::Bottom => "╹",
+            Side::Left => "╺",
+        }
+    }
+
+    fn single_cell(&self) -> &'static str {
+        "■"
+    }
 }
 
 /// Quadrant inside border symbol set.
@@ -1095,6 +1245,10 @@ impl BorderSymbolSet for QuadrantInsideSymbolSet {
             (_, _, _, _) => "█",
         }
     }
+
+    fn single_cell(&self) -> &'static str {
+        "█"
+    }
 }
 
 /// Quadrant outside symbol set.
@@ -1236,6 +1390,10 @@ impl BorderSymbolSet for QuadrantOutsideSymbolSet {
             (_, _, _, _) => "█",
         }
     }
+
+    fn single_cell(&self) -> &'static str {
+        "█"
+    }
 }
 
 /// Uses plain ascii characters to draw a border. Uses '+', '-' and '|'.
@@ -1327,6 +1485,14 @@ impl BorderSymbolSet for AsciiSymbolSet {
     ) -> &'static str {
         "+"
     }
+
+    fn line_end(&self, _side: Side) -> &'static str {
+        "+"
+    }
+
+    fn single_cell(&self) -> &'static str {
+        "+"
+    }
 }
 
 /// Draws a border using only '*'.
@@ -1355,4 +1521,159 @@ impl BorderSymbolSet for StarSymbolSet {
     ) -> &'static str {
         "*"
     }
+
+    fn single_cell(&self) -> &'static str {
+        "*"
+    }
+}
+
+/// Number of dashes per cell for [DashedSymbolSet] and
+/// [ThickDashedSymbolSet].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DashPattern {
+    /// Two dashes per cell. `╌ ╎`
+    #[default]
+    Double,
+    /// Three dashes per cell. `┄ ┆`
+    Triple,
+    /// Four dashes per cell. Looks dotted. `┈ ┊`
+    Quadruple,
+}
+
+/// Dashed border symbol set.
+///
+/// Only the straight parts of the border are dashed, the corners
+/// and junctions use the solid glyphs of [PlainSymbolSet].
+/// Other borders connect to it as if it were a plain border.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DashedSymbolSet {
+    /// Dash pattern.
+    pub pattern: DashPattern,
+}
+
+impl DashedSymbolSet {
+    /// New dashed symbol set.
+    pub fn new(pattern: DashPattern) -> Self {
+        Self { pattern }
+    }
+}
+
+impl BorderSymbolSet for DashedSymbolSet {
+    fn border_type(&self) -> BorderType {
+        BorderType::Plain
+    }
+
+    fn symbol(&self, side: Side, symbol: BorderSymbol) -> &'static str {
+        match symbol {
+            BorderSymbol::SideRegular | BorderSymbol::SideOverlap(_, plain!()) => {
+                match (side, self.pattern) {
+                    (Side::Top | Side::Bottom, DashPattern::Double) => "╌",
+                    (Side::Top | Side::Bottom, DashPattern::Triple) => "┄",
+                    (Side::Top | Side::Bottom, DashPattern::Quadruple) => "┈",
+                    (Side::Left | Side::Right, DashPattern::Double) => "╎",
+                    (Side::Left | Side::Right, DashPattern::Triple) => "┆",
+                    (Side::Left | Side::Right, DashPattern::Quadruple) => "┊",
+                }
+            }
+            _ => PlainSymbolSet.symbol(side, symbol),
+        }
+    }
+
+    fn crossing(
+        &self,
+        top_side: Side,
+        top: BorderType,
+        right_side: Side,
+        right: BorderType,
+        bottom_side: Side,
+        bottom: BorderType,
+        left_side: Side,
+        left: BorderType,
+    ) -> &'static str {
+        PlainSymbolSet.crossing(
+            top_side,
+            top,
+            right_side,
+            right,
+            bottom_side,
+            bottom,
+            left_side,
+            left,
+        )
+    }
+
+    fn line_end(&self, side: Side) -> &'static str {
+        PlainSymbolSet.line_end(side)
+    }
+}
+
+/// Thick dashed border symbol set.
+///
+/// Only the straight parts of the border are dashed, the corners
+/// and junctions use the solid glyphs of [ThickSymbolSet].
+/// Other borders connect to it as if it were a thick border.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ThickDashedSymbolSet {
+    /// Dash pattern.
+    pub pattern: DashPattern,
+}
+
+impl ThickDashedSymbolSet {
+    /// New thick dashed symbol set.
+    pub fn new(pattern: DashPattern) -> Self {
+        Self { pattern }
+    }
+}
+
+impl BorderSymbolSet for ThickDashedSymbolSet {
+    fn border_type(&self) -> BorderType {
+        BorderType::Thick
+    }
+
+    fn symbol(&self, side: Side, symbol: BorderSymbol) -> &'static str {
+        match symbol {
+            BorderSymbol::SideRegular | BorderSymbol::SideOverlap(_, BorderType::Thick) => {
+                match (side, self.pattern) {
+                    (Side::Top | Side::Bottom, DashPattern::Double) => "╍",
+                    (Side::Top | Side::Bottom, DashPattern::Triple) => "┅",
+                    (Side::Top | Side::Bottom, DashPattern::Quadruple) => "┉",
+                    (Side::Left | Side::Right, DashPattern::Double) => "╏",
+                    (Side::Left | Side::Right, DashPattern::Triple) => "┇",
+                    (Side::Left | Side::Right, DashPattern::Quadruple) => "┋",
+                }
+            }
+            _ => ThickSymbolSet.symbol(side, symbol),
+        }
+    }
+
+    fn crossing(
+        &self,
+        top_side: Side,
+        top: BorderType,
+        right_side: Side,
+        right: BorderType,
+        bottom_side: Side,
+        bottom: BorderType,
+        left_side: Side,
+        left: BorderType,
+    ) -> &'static str {
+        ThickSymbolSet.crossing(
+            top_side,
+            top,
+            right_side,
+            right,
+            bottom_side,
+            bottom,
+            left_side,
+            left,
+        )
+    }
+
+    fn line_end(&self, side: Side) -> &'static str {
+        ThickSymbolSet.line_end(side)
+    }
+
+    fn single_cell(&self) -> &'static str {
+        ThickSymbolSet.single_cell()
+    }
 }
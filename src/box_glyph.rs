@@ -0,0 +1,245 @@
+use crate::border_symbols::DashPattern;
+use ratatui::widgets::BorderType;
+
+///
+/// Weight of one arm of a box drawing glyph.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weight {
+    /// No arm.
+    #[default]
+    None,
+    /// Light line.
+    Light,
+    /// Heavy line.
+    Heavy,
+    /// Double line.
+    Double,
+    /// Light dashed line.
+    LightDashed(DashPattern),
+    /// Heavy dashed line.
+    HeavyDashed(DashPattern),
+}
+
+impl Weight {
+    ///
+    /// Weight of the lines of a border type.
+    ///
+    /// The quadrant borders are not drawn with box drawing
+    /// glyphs, they count as heavy.
+    ///
+    pub fn from_border_type(border_type: BorderType) -> Self {
+        match border_type {
+            BorderType::Plain | BorderType::Rounded => Weight::Light,
+            BorderType::Double => Weight::Double,
+            BorderType::Thick | BorderType::QuadrantInside | BorderType::QuadrantOutside => {
+                Weight::Heavy
+            }
+        }
+    }
+
+    ///
+    /// Same weight without the dashes.
+    ///
+    pub fn solid(self) -> Self {
+        match self {
+            Weight::LightDashed(_) => Weight::Light,
+            Weight::HeavyDashed(_) => Weight::Heavy,
+            v => v,
+        }
+    }
+
+    // Index into the glyph tables.
+    fn index(self) -> usize {
+        match self {
+            Weight::None => 0,
+            Weight::Light | Weight::LightDashed(_) => 1,
+            Weight::Heavy | Weight::HeavyDashed(_) | Weight::Double => 2,
+        }
+    }
+}
+
+///
+/// Resolves the box drawing glyph for a cell with the given arms.
+///
+/// Uses the glyphs of the Unicode box drawing block U+2500.
+/// Not every combination of weights exists there, the fallbacks
+/// are:
+///
+/// * Dashed lines only exist as straight lines. All other
+///   glyphs use the solid weight instead.
+/// * Heavy and double arms never meet in one glyph. If there
+///   is a double arm, heavy arms are drawn double too.
+/// * A single double arm is drawn as the full double line.
+/// * Light and double arms only meet if both arms along an axis
+///   have the same weight. Otherwise the mixed axis takes the
+///   weight that is in the minority among all arms of the glyph,
+///   so a connection to another border stays visible. On a tie
+///   the double weight wins.
+/// * No arms at all give a space.
+///
+pub fn resolve(up: Weight, right: Weight, down: Weight, left: Weight) -> &'static str {
+    if let Some(glyph) = dashed(up, right, down, left) {
+        return glyph;
+    }
+
+    let mut arms = [up, right, down, left].map(Weight::solid);
+    if arms.iter().all(|v| *v == Weight::None) {
+        return " ";
+    }
+
+    if arms.contains(&Weight::Double) {
+        for arm in &mut arms {
+            if *arm == Weight::Heavy {
+                *arm = Weight::Double;
+            }
+        }
+        if arms.iter().filter(|v| **v != Weight::None).count() == 1 {
+            if let Some(n) = arms.iter().position(|v| *v == Weight::Double) {
+                arms[(n + 2) % 4] = Weight::Double;
+            }
+        }
+
+        let doubles = arms.iter().filter(|v| **v == Weight::Double).count();
+        let lights = arms.iter().filter(|v| **v == Weight::Light).count();
+        let minority = if doubles <= lights {
+            Weight::Double
+        } else {
+            Weight::Light
+        };
+        for (a, b) in [(0, 2), (1, 3)] {
+            if arms[a] != Weight::None && arms[b] != Weight::None && arms[a] != arms[b] {
+                arms[a] = minority;
+                arms[b] = minority;
+            }
+        }
+
+        LIGHT_DOUBLE[table_index(arms)]
+    } else {
+        LIGHT_HEAVY[table_index(arms)]
+    }
+}
+
+///
+/// Arms of a glyph of the box drawing block U+2500.
+///
+/// This is the reverse of [resolve]. Returns the weights of the
+/// arms up, right, down and left, or None if the glyph is not
+/// a box drawing glyph. The rounded corners count as light,
+/// and the diagonals `╱ ╲ ╳` have no arms and give None too.
+///
+pub fn parse(glyph: &str) -> Option<[Weight; 4]> {
+    use Weight::*;
+
+    if glyph.is_empty() {
+        return Option::None;
+    }
+    if let Some(n) = LIGHT_HEAVY.iter().position(|v| *v == glyph) {
+        return Some(table_arms(n, Heavy));
+    }
+    if let Some(n) = LIGHT_DOUBLE.iter().position(|v| *v == glyph) {
+        return Some(table_arms(n, Double));
+    }
+
+    let arms = match glyph {
+        "╭" => [None, Light, Light, None],
+        "╮" => [None, None, Light, Light],
+        "╯" => [Light, None, None, Light],
+        "╰" => [Light, Light, None, None],
+        _ => {
+            let (weight, vertical) = match glyph {
+                "╌" => (LightDashed(DashPattern::Double), false),
+                "┄" => (LightDashed(DashPattern::Triple), false),
+                "┈" => (LightDashed(DashPattern::Quadruple), false),
+                "╎" => (LightDashed(DashPattern::Double), true),
+                "┆" => (LightDashed(DashPattern::Triple), true),
+                "┊" => (LightDashed(DashPattern::Quadruple), true),
+                "╍" => (HeavyDashed(DashPattern::Double), false),
+                "┅" => (HeavyDashed(DashPattern::Triple), false),
+                "┉" => (HeavyDashed(DashPattern::Quadruple), false),
+                "╏" => (HeavyDashed(DashPattern::Double), true),
+                "┇" => (HeavyDashed(DashPattern::Triple), true),
+                "┋" => (HeavyDashed(DashPattern::Quadruple), true),
+                _ => return Option::None,
+            };
+            if vertical {
+                [weight, None, weight, None]
+            } else {
+                [None, weight, None, weight]
+            }
+        }
+    };
+    Some(arms)
+}
+
+/// Dashed straight lines.
+fn dashed(up: Weight, right: Weight, down: Weight, left: Weight) -> Option<&'static str> {
+    let (line, vertical) = if up == down && right == Weight::None && left == Weight::None {
+        (up, true)
+    } else if right == left && up == Weight::None && down == Weight::None {
+        (right, false)
+    } else {
+        return None;
+    };
+
+    let glyph = match (line, vertical) {
+        (Weight::LightDashed(DashPattern::Double), false) => "╌",
+        (Weight::LightDashed(DashPattern::Triple), false) => "┄",
+        (Weight::LightDashed(DashPattern::Quadruple), false) => "┈",
+        (Weight::LightDashed(DashPattern::Double), true) => "╎",
+        (Weight::LightDashed(DashPattern::Triple), true) => "┆",
+        (Weight::LightDashed(DashPattern::Quadruple), true) => "┊",
+        (Weight::HeavyDashed(DashPattern::Double), false) => "╍",
+        (Weight::HeavyDashed(DashPattern::Triple), false) => "┅",
+        (Weight::HeavyDashed(DashPattern::Quadruple), false) => "┉",
+        (Weight::HeavyDashed(DashPattern::Double), true) => "╏",
+        (Weight::HeavyDashed(DashPattern::Triple), true) => "┇",
+        (Weight::HeavyDashed(DashPattern::Quadruple), true) => "┋",
+        _ => return None,
+    };
+    Some(glyph)
+}
+
+fn table_index(arms: [Weight; 4]) -> usize {
+    arms.iter().fold(0, |idx, arm| idx * 3 + arm.index())
+}
+
+/// Arms for an index into the glyph tables.
+fn table_arms(index: usize, second: Weight) -> [Weight; 4] {
+    [27, 9, 3, 1].map(|v| match (index / v) % 3 {
+        0 => Weight::None,
+        1 => Weight::Light,
+        _ => second,
+    })
+}
+
+// Glyphs indexed by up, right, down, left with
+// 0 = none, 1 = light, 2 = heavy.
+#[rustfmt::skip]
+const LIGHT_HEAVY: [&str; 81] = [
+    "", "╴", "╸", "╷", "┐", "┑", "╻", "┒", "┓",
+    "╶", "─", "╾", "┌", "┬", "┭", "┎", "┰", "┱",
+    "╺", "╼", "━", "┍", "┮", "┯", "┏", "┲", "┳",
+    "╵", "┘", "┙", "│", "┤", "┥", "╽", "┧", "┪",
+    "└", "┴", "┵", "├", "┼", "┽", "┟", "╁", "╅",
+    "┕", "┶", "┷", "┝", "┾", "┿", "┢", "╆", "╈",
+    "╹", "┚", "┛", "╿", "┦", "┩", "┃", "┨", "┫",
+    "┖", "┸", "┹", "┞", "╀", "╃", "┠", "╂", "╉",
+    "┗", "┺", "┻", "┡", "╄", "╇", "┣", "╊", "╋",
+];
+
+// Glyphs indexed by up, right, down, left with
+// 0 = none, 1 = light, 2 = double.
+// Empty where Unicode has no glyph.
+#[rustfmt::skip]
+const LIGHT_DOUBLE: [&str; 81] = [
+    "", "╴", "", "╷", "┐", "╕", "", "╖", "╗",
+    "╶", "─", "", "┌", "┬", "", "╓", "╥", "",
+    "", "", "═", "╒", "", "╤", "╔", "", "╦",
+    "╵", "┘", "╛", "│", "┤", "╡", "", "", "",
+    "└", "┴", "", "├", "┼", "", "", "", "",
+    "╘", "", "╧", "╞", "", "╪", "", "", "",
+    "", "╜", "╝", "", "", "", "║", "╢", "╣",
+    "╙", "╨", "", "", "", "", "╟", "╫", "",
+    "╚", "", "╩", "", "", "", "╠", "", "╬",
+];
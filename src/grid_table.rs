@@ -0,0 +1,260 @@
+use crate::block_grid::BlockGrid;
+use crate::border_symbols::symbol_set;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::Style;
+use ratatui::text::Text;
+use ratatui::widgets::{BorderType, Widget};
+
+///
+/// A table with grid lines between the cells.
+///
+/// Renders the cells with one cell wide gaps between them, and uses
+/// a [BlockGrid] to draw the outer border, the column lines, the
+/// separator below the header and, optionally, lines between the rows.
+///
+/// Each row is as high as its highest cell. Rows that don't fit into
+/// the area are cut off.
+///
+#[derive(Debug, Clone)]
+pub struct GridTable<'a> {
+    rows: Vec<Vec<Text<'a>>>,
+    header: Option<Vec<Text<'a>>>,
+    widths: Vec<Constraint>,
+
+    style: Style,
+    header_style: Style,
+
+    border_type: BorderType,
+    border_style: Style,
+    line_type: BorderType,
+    line_style: Style,
+    header_separator: Option<BorderType>,
+    row_separators: bool,
+}
+
+impl Default for GridTable<'_> {
+    fn default() -> Self {
+        Self {
+            rows: Default::default(),
+            header: None,
+            widths: Default::default(),
+            style: Default::default(),
+            header_style: Default::default(),
+            border_type: BorderType::Plain,
+            border_style: Default::default(),
+            line_type: BorderType::Plain,
+            line_style: Default::default(),
+            header_separator: None,
+            row_separators: false,
+        }
+    }
+}
+
+impl<'a> GridTable<'a> {
+    ///
+    /// New table.
+    ///
+    /// * rows - The cells of each row.
+    /// * widths - Constraints for the column widths. If this is
+    ///   empty, each column is as wide as its widest cell.
+    ///
+    pub fn new<R, C, T>(rows: R, widths: impl IntoIterator<Item = Constraint>) -> Self
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = T>,
+        T: Into<Text<'a>>,
+    {
+        Self {
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(Into::into).collect())
+                .collect(),
+            widths: widths.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Header row.
+    ///
+    /// The header is separated from the rows by a line.
+    ///
+    pub fn header<T: Into<Text<'a>>>(mut self, header: impl IntoIterator<Item = T>) -> Self {
+        self.header = Some(header.into_iter().map(Into::into).collect());
+        self
+    }
+
+    ///
+    /// Constraints for the column widths.
+    ///
+    pub fn widths(mut self, widths: impl IntoIterator<Item = Constraint>) -> Self {
+        self.widths = widths.into_iter().collect();
+        self
+    }
+
+    ///
+    /// Base style for the whole table.
+    ///
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    ///
+    /// Style for the header cells.
+    ///
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    ///
+    /// Border type for the outer border.
+    ///
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    ///
+    /// Style for the outer border.
+    ///
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    ///
+    /// Border type for the column lines and the row separators.
+    ///
+    pub fn line_type(mut self, border_type: BorderType) -> Self {
+        self.line_type = border_type;
+        self
+    }
+
+    ///
+    /// Style for all the lines inside the table.
+    ///
+    pub fn line_style(mut self, style: Style) -> Self {
+        self.line_style = style;
+        self
+    }
+
+    ///
+    /// Border type for the line below the header.
+    ///
+    /// Defaults to the [line_type](GridTable::line_type).
+    ///
+    pub fn header_separator(mut self, border_type: BorderType) -> Self {
+        self.header_separator = Some(border_type);
+        self
+    }
+
+    ///
+    /// Render a line between all the rows.
+    ///
+    pub fn row_separators(mut self, row_separators: bool) -> Self {
+        self.row_separators = row_separators;
+        self
+    }
+
+    /// Column widths. Uses the width of the widest cell
+    /// if no constraints are given.
+    fn constraints(&self) -> Vec<Constraint> {
+        if !self.widths.is_empty() {
+            return self.widths.clone();
+        }
+        let mut widths = Vec::new();
+        for row in self.header.iter().chain(self.rows.iter()) {
+            if widths.len() < row.len() {
+                widths.resize(row.len(), 0);
+            }
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.width() as u16);
+            }
+        }
+        widths.into_iter().map(Constraint::Length).collect()
+    }
+}
+
+/// Height of a row.
+fn row_height(row: &[Text<'_>]) -> u16 {
+    row.iter()
+        .map(|cell| cell.height() as u16)
+        .max()
+        .unwrap_or_default()
+        .max(1)
+}
+
+impl Widget for GridTable<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &GridTable<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+
+        let inner = Rect::new(
+            area.x + 1,
+            area.y + 1,
+            area.width.saturating_sub(2),
+            area.height.saturating_sub(2),
+        );
+        let columns = Layout::horizontal(self.constraints())
+            .spacing(1)
+            .split(inner);
+
+        let mut grid = BlockGrid::new()
+            .border_type(self.border_type)
+            .border_style(self.border_style)
+            .horizontal_border_type(self.line_type)
+            .horizontal_style(self.line_style)
+            .vertical_border_type(self.line_type)
+            .vertical_style(self.line_style);
+        for column in columns.iter().take(columns.len().saturating_sub(1)) {
+            grid = grid.vertical(column.right() - area.x);
+        }
+
+        // cells and horizontal lines.
+        let mut y = inner.y;
+        let header = self.header.iter().map(|row| (row, self.header_style));
+        let rows = self.rows.iter().map(|row| (row, Style::new()));
+        for (n, (row, style)) in header.chain(rows).enumerate() {
+            if y >= inner.bottom() {
+                break;
+            }
+            let height = row_height(row);
+            for (cell, column) in row.iter().zip(columns.iter()) {
+                let cell_area = Rect::new(column.x, y, column.width, height).intersection(inner);
+                buf.set_style(cell_area, style);
+                cell.clone().render(cell_area, buf);
+            }
+            y += height;
+
+            let last = n + 1 == self.rows.len() + self.header.iter().len();
+            if n == 0 && self.header.is_some() {
+                let border = self.header_separator.unwrap_or(self.line_type);
+                grid = grid.horizontal_line_boxed(y - area.y, symbol_set(border), self.line_style);
+                y += 1;
+            } else if self.row_separators && !last {
+                grid = grid.horizontal(y - area.y);
+                y += 1;
+            }
+        }
+
+        grid.render(area, buf);
+    }
+}
@@ -0,0 +1,425 @@
+use crate::box_glyph::{self, Weight};
+use crate::{BorderSymbol, Side};
+use ratatui::widgets::BorderType;
+
+///
+/// One arm of a [Junction].
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arm {
+    /// Line kind of the arm.
+    pub border_type: BorderType,
+    /// Side of the other area this arm belongs to.
+    /// None for the arms of the border itself.
+    ///
+    /// This is the same side as in [BorderSymbol], and
+    /// is only relevant for the quadrant sets.
+    pub side: Option<Side>,
+}
+
+///
+/// Junction of lines described by its arms.
+///
+/// This is an alternative to [BorderSymbol], that doesn't
+/// describe the junction relative to the start, end and
+/// side of one border, but with the explicit arms going
+/// up, right, down and left.
+///
+/// Every BorderSymbol can be converted to a Junction and back
+/// without loss, see [Junction::from_symbol] and [Junction::to_symbol].
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Junction {
+    /// Arm going up.
+    pub up: Option<Arm>,
+    /// Arm going right.
+    pub right: Option<Arm>,
+    /// Arm going down.
+    pub down: Option<Arm>,
+    /// Arm going left.
+    pub left: Option<Arm>,
+}
+
+impl Arm {
+    ///
+    /// Arm of the border itself.
+    ///
+    pub fn own(border_type: BorderType) -> Self {
+        Self {
+            border_type,
+            side: None,
+        }
+    }
+
+    ///
+    /// Arm of the border of another area.
+    ///
+    pub fn other(side: Side, border_type: BorderType) -> Self {
+        Self {
+            border_type,
+            side: Some(side),
+        }
+    }
+}
+
+// Indices of the arms.
+const UP: usize = 0;
+const RIGHT: usize = 1;
+const DOWN: usize = 2;
+const LEFT: usize = 3;
+
+/// Direction of the arms of a BorderSymbol along one side.
+struct Geometry {
+    start: [usize; 2],
+    start_angled: usize,
+    start_prolonged: usize,
+    end: [usize; 2],
+    end_angled: usize,
+    end_prolonged: usize,
+    line: [usize; 2],
+    outward: usize,
+    inward: usize,
+}
+
+fn geometry(side: Side) -> Geometry {
+    match side {
+        Side::Top => Geometry {
+            start: [RIGHT, DOWN],
+            start_angled: UP,
+            start_prolonged: LEFT,
+            end: [LEFT, DOWN],
+            end_angled: UP,
+            end_prolonged: RIGHT,
+            line: [LEFT, RIGHT],
+            outward: UP,
+            inward: DOWN,
+        },
+        Side::Bottom => Geometry {
+            start: [RIGHT, UP],
+            start_angled: DOWN,
+            start_prolonged: LEFT,
+            end: [LEFT, UP],
+            end_angled: DOWN,
+            end_prolonged: RIGHT,
+            line: [LEFT, RIGHT],
+            outward: DOWN,
+            inward: UP,
+        },
+        Side::Right => Geometry {
+            start: [LEFT, DOWN],
+            start_angled: RIGHT,
+            start_prolonged: UP,
+            end: [LEFT, UP],
+            end_angled: RIGHT,
+            end_prolonged: DOWN,
+            line: [UP, DOWN],
+            outward: RIGHT,
+            inward: LEFT,
+        },
+        Side::Left => Geometry {
+            start: [RIGHT, DOWN],
+            start_angled: LEFT,
+            start_prolonged: UP,
+            end: [RIGHT, UP],
+            end_angled: LEFT,
+            end_prolonged: DOWN,
+            line: [UP, DOWN],
+            outward: LEFT,
+            inward: RIGHT,
+        },
+    }
+}
+
+/// The given side first, then the other sides.
+/// The corners belong to top and bottom, they come first.
+fn sides(side: Side) -> impl Iterator<Item = Side> {
+    std::iter::once(side).chain(
+        [Side::Top, Side::Bottom, Side::Left, Side::Right]
+            .into_iter()
+            .filter(move |v| *v != side),
+    )
+}
+
+impl Junction {
+    ///
+    /// New junction.
+    ///
+    pub fn new(up: Option<Arm>, right: Option<Arm>, down: Option<Arm>, left: Option<Arm>) -> Self {
+        Self {
+            up,
+            right,
+            down,
+            left,
+        }
+    }
+
+    ///
+    /// Junction for a BorderSymbol.
+    ///
+    /// side: Which side of the area.
+    /// symbol: Symbol definition.
+    /// border_type: Border type of the area.
+    ///
+    pub fn from_symbol(side: Side, symbol: BorderSymbol, border_type: BorderType) -> Self {
+        let g = geometry(side);
+        let own = Some(Arm::own(border_type));
+        let mut arms = [None; 4];
+
+        let (base, extra) = match symbol {
+            BorderSymbol::StartCornerRegular => (g.start, [None, None]),
+            BorderSymbol::StartCornerAngled(s, b) => {
+                (g.start, [Some((g.start_angled, s, b)), None])
+            }
+            BorderSymbol::StartCornerProlonged(s, b) => {
+                (g.start, [Some((g.start_prolonged, s, b)), None])
+            }
+            BorderSymbol::StartCornerCrossed(s0, b0, s1, b1) => (
+                g.start,
+                [
+                    Some((g.start_angled, s0, b0)),
+                    Some((g.start_prolonged, s1, b1)),
+                ],
+            ),
+            BorderSymbol::SideRegular => (g.line, [None, None]),
+            BorderSymbol::SideOverlap(s, b) => {
+                arms[g.line[0]] = Some(Arm::other(s, b));
+                arms[g.line[1]] = Some(Arm::other(s, b));
+                return Self::from_arms(arms);
+            }
+            BorderSymbol::SideOutward(s, b) => (g.line, [Some((g.outward, s, b)), None]),
+            BorderSymbol::SideInward(s, b) => (g.line, [Some((g.inward, s, b)), None]),
+            BorderSymbol::SideCrossed(s0, b0, s1, b1) => (
+                g.line,
+                [Some((g.outward, s0, b0)), Some((g.inward, s1, b1))],
+            ),
+            BorderSymbol::EndCornerRegular => (g.end, [None, None]),
+            BorderSymbol::EndCornerAngled(s, b) => (g.end, [Some((g.end_angled, s, b)), None]),
+            BorderSymbol::EndCornerProlonged(s, b) => {
+                (g.end, [Some((g.end_prolonged, s, b)), None])
+            }
+            BorderSymbol::EndCornerCrossed(s0, b0, s1, b1) => (
+                g.end,
+                [
+                    Some((g.end_angled, s0, b0)),
+                    Some((g.end_prolonged, s1, b1)),
+                ],
+            ),
+        };
+
+        for arm in base {
+            arms[arm] = own;
+        }
+        for (arm, side, border_type) in extra.into_iter().flatten() {
+            arms[arm] = Some(Arm::other(side, border_type));
+        }
+        Self::from_arms(arms)
+    }
+
+    ///
+    /// BorderSymbol for this junction along the given side.
+    ///
+    /// The arms of the border itself must all have the same
+    /// border type. Returns None if the junction can't be written
+    /// as a BorderSymbol for this side.
+    ///
+    pub fn to_symbol(&self, side: Side) -> Option<BorderSymbol> {
+        let g = geometry(side);
+        let arms = self.arms();
+
+        let mut own_types = arms.iter().flatten().filter(|v| v.side.is_none());
+        if let Some(first) = own_types.next() {
+            if own_types.any(|v| v.border_type != first.border_type) {
+                return None;
+            }
+        }
+
+        let is_own = |n: usize| matches!(arms[n], Some(Arm { side: None, .. }));
+        // Ok(None) for no arm, Ok(Some) for the arm of another area,
+        // Err for an arm of the border itself.
+        let other = |n: usize| match arms[n] {
+            None => Ok(None),
+            Some(Arm {
+                border_type,
+                side: Some(side),
+            }) => Ok(Some((side, border_type))),
+            Some(Arm { side: None, .. }) => Err(()),
+        };
+
+        // The other two arms for a pair of own arms. A pattern that
+        // doesn't match falls through to the next one.
+        let rest = |pair: [usize; 2], a: usize, b: usize| {
+            if is_own(pair[0]) && is_own(pair[1]) {
+                Some((other(a).ok()?, other(b).ok()?))
+            } else {
+                None
+            }
+        };
+
+        if let Some((angled, prolonged)) = rest(g.start, g.start_angled, g.start_prolonged) {
+            return Some(match (angled, prolonged) {
+                (None, None) => BorderSymbol::StartCornerRegular,
+                (Some((s, b)), None) => BorderSymbol::StartCornerAngled(s, b),
+                (None, Some((s, b))) => BorderSymbol::StartCornerProlonged(s, b),
+                (Some((s0, b0)), Some((s1, b1))) => {
+                    BorderSymbol::StartCornerCrossed(s0, b0, s1, b1)
+                }
+            });
+        }
+        if let Some((angled, prolonged)) = rest(g.end, g.end_angled, g.end_prolonged) {
+            return Some(match (angled, prolonged) {
+                (None, None) => BorderSymbol::EndCornerRegular,
+                (Some((s, b)), None) => BorderSymbol::EndCornerAngled(s, b),
+                (None, Some((s, b))) => BorderSymbol::EndCornerProlonged(s, b),
+                (Some((s0, b0)), Some((s1, b1))) => BorderSymbol::EndCornerCrossed(s0, b0, s1, b1),
+            });
+        }
+        if let Some((outward, inward)) = rest(g.line, g.outward, g.inward) {
+            return Some(match (outward, inward) {
+                (None, None) => BorderSymbol::SideRegular,
+                (Some((s, b)), None) => BorderSymbol::SideOutward(s, b),
+                (None, Some((s, b))) => BorderSymbol::SideInward(s, b),
+                (Some((s0, b0)), Some((s1, b1))) => BorderSymbol::SideCrossed(s0, b0, s1, b1),
+            });
+        }
+
+        match (
+            other(g.line[0]),
+            other(g.line[1]),
+            arms[g.outward],
+            arms[g.inward],
+        ) {
+            (Ok(Some(v0)), Ok(Some(v1)), None, None) if v0 == v1 => {
+                Some(BorderSymbol::SideOverlap(v0.0, v0.1))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// BorderSymbol for this junction along the given side, or
+    /// along one of the other sides if it doesn't fit there.
+    ///
+    pub(crate) fn any_symbol(&self, side: Side) -> Option<(Side, BorderSymbol)> {
+        sides(side).find_map(|side| Some((side, self.to_symbol(side)?)))
+    }
+
+    ///
+    /// Nearest BorderSymbol for a junction that has none.
+    ///
+    /// Takes two arms in a line, or two arms of a corner, as the
+    /// border itself and all other arms as arms of other areas.
+    /// Lines are preferred, so a T stays a T. Returns None if
+    /// there are less than two arms.
+    ///
+    pub(crate) fn nearest_symbol(&self, side: Side) -> Option<(Side, BorderSymbol)> {
+        let arms = self.arms();
+        let pairs = |g: Geometry| [g.line, g.start, g.end];
+
+        (0..3).find_map(|n| {
+            sides(side).find_map(|side| {
+                let pair = pairs(geometry(side))[n];
+                let own = arms[pair[0]].zip(arms[pair[1]])?.0;
+                let mut nearest =
+                    arms.map(|v| v.map(|v| Arm::other(v.side.unwrap_or(side), v.border_type)));
+                nearest[pair[0]] = Some(Arm::own(own.border_type));
+                nearest[pair[1]] = Some(Arm::own(own.border_type));
+                Some((side, Self::from_arms(nearest).to_symbol(side)?))
+            })
+        })
+    }
+
+    ///
+    /// Junction for a glyph found in a buffer.
+    ///
+    /// Covers the box drawing block U+2500 and the glyphs of
+    /// the quadrant and ascii symbol sets. Returns None for
+    /// all other glyphs. All arms have no side, the glyph
+    /// doesn't tell which border they belong to.
+    /// [BorderSymbolSet::junction](crate::BorderSymbolSet::junction)
+    /// draws the same glyph again for the glyphs of the box drawing
+    /// sets.
+    ///
+    /// * Light arms are [BorderType::Plain], heavy ones
+    ///   [BorderType::Thick] and double ones [BorderType::Double].
+    ///   Dashed lines count as solid, the rounded corners are
+    ///   [BorderType::Rounded].
+    /// * The diagonals `╱ ╲ ╳` have no arms and give None.
+    /// * The quadrant glyphs are read by their shape. Single
+    ///   quadrants are the corners of [BorderType::QuadrantInside],
+    ///   three quadrants the corners of [BorderType::QuadrantOutside].
+    ///   Half blocks are straight lines, and the diagonal quadrants
+    ///   and the full block are crossings. Both quadrant sets use
+    ///   these, they are reported as [BorderType::QuadrantInside].
+    /// * Ascii `-` and `|` are plain lines. `+` is used for every
+    ///   corner and junction, it's reported as a plain crossing.
+    ///
+    pub fn from_glyph(glyph: &str) -> Option<Self> {
+        use BorderType::*;
+
+        let arms = |border_type: BorderType, arms: [bool; 4]| {
+            let arm = |v: bool| v.then_some(Arm::own(border_type));
+            Some(Self::new(
+                arm(arms[UP]),
+                arm(arms[RIGHT]),
+                arm(arms[DOWN]),
+                arm(arms[LEFT]),
+            ))
+        };
+
+        match glyph {
+            "╭" | "╮" | "╯" | "╰" => {
+                let weights = box_glyph::parse(glyph)?;
+                arms(Rounded, weights.map(|v| v != Weight::None))
+            }
+            "▗" => arms(QuadrantInside, [false, true, true, false]),
+            "▖" => arms(QuadrantInside, [false, false, true, true]),
+            "▝" => arms(QuadrantInside, [true, true, false, false]),
+            "▘" => arms(QuadrantInside, [true, false, false, true]),
+            "▛" => arms(QuadrantOutside, [false, true, true, false]),
+            "▜" => arms(QuadrantOutside, [false, false, true, true]),
+            "▙" => arms(QuadrantOutside, [true, true, false, false]),
+            "▟" => arms(QuadrantOutside, [true, false, false, true]),
+            "▀" | "▄" => arms(QuadrantInside, [false, true, false, true]),
+            "▌" | "▐" => arms(QuadrantInside, [true, false, true, false]),
+            "▚" | "▞" | "█" => arms(QuadrantInside, [true, true, true, true]),
+            "-" => arms(Plain, [false, true, false, true]),
+            "|" => arms(Plain, [true, false, true, false]),
+            "+" => arms(Plain, [true, true, true, true]),
+            _ => {
+                let weights = box_glyph::parse(glyph)?;
+                let arm = |v: Weight| {
+                    let border_type = match v.solid() {
+                        Weight::None => return None,
+                        Weight::Light => Plain,
+                        Weight::Double => Double,
+                        _ => Thick,
+                    };
+                    Some(Arm::own(border_type))
+                };
+                Some(Self::new(
+                    arm(weights[UP]),
+                    arm(weights[RIGHT]),
+                    arm(weights[DOWN]),
+                    arm(weights[LEFT]),
+                ))
+            }
+        }
+    }
+
+    ///
+    /// Number of arms.
+    ///
+    pub fn count(&self) -> usize {
+        self.arms().iter().flatten().count()
+    }
+
+    /// Arms as array up, right, down, left.
+    fn arms(&self) -> [Option<Arm>; 4] {
+        [self.up, self.right, self.down, self.left]
+    }
+
+    /// Junction from an array up, right, down, left.
+    fn from_arms(arms: [Option<Arm>; 4]) -> Self {
+        Self::new(arms[UP], arms[RIGHT], arms[DOWN], arms[LEFT])
+    }
+}
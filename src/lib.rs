@@ -3,11 +3,21 @@
 pub mod block_border;
 pub mod block_connect;
 pub mod block_grid;
+pub mod block_layout;
+pub mod border_style;
 pub mod border_symbols;
+pub mod box_glyph;
+pub mod grid_table;
+pub mod junction;
+pub mod merge_borders;
+pub mod split_border;
 
+use crate::box_glyph::Weight;
+use crate::junction::{Arm, Junction};
 use dyn_clone::DynClone;
 use ratatui::widgets::BorderType;
-use std::fmt::Debug;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
 
 /// Names for the sides of an area.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +32,31 @@ pub enum Side {
     Left,
 }
 
+/// Errors for borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BorderError {
+    /// The area is too small to hold a prefabricated border.
+    DegenerateArea,
+    /// The position doesn't lie on the border.
+    OffBorder,
+    /// The border was built for a different size, and its
+    /// connections don't fit the area.
+    SizeMismatch,
+}
+
+impl Display for BorderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BorderError::DegenerateArea => write!(f, "area too small for a border"),
+            BorderError::OffBorder => write!(f, "position not on the border"),
+            BorderError::SizeMismatch => write!(f, "connections don't fit the size of the area"),
+        }
+    }
+}
+
+impl Error for BorderError {}
+
 /// Symbol set trait
 pub trait BorderSymbolSet: DynClone {
     ///
@@ -43,8 +78,8 @@ pub trait BorderSymbolSet: DynClone {
     /// The number of parameters comes from handling Quadrant crossings.
     /// ![schematics](https://raw.githubusercontent.com/thscharler/ratatui-block/refs/heads/master/diagram/quadrant_inside_cross.png)
     ///
-    /// __Remark__
-    /// Not needed for drawing Rects or grids, but a bit of a missing link otherwise.
+    /// This is used for the crossings of grid lines, where the lines
+    /// meeting at the crossing can all be of different types.
     ///
     #[allow(clippy::too_many_arguments)]
     fn crossing(
@@ -58,6 +93,109 @@ pub trait BorderSymbolSet: DynClone {
         left_side: Side,
         left: BorderType,
     ) -> &'static str;
+
+    ///
+    /// Get the symbol for a junction of lines.
+    ///
+    /// side: Which side of the area.
+    /// junction: Arms of the junction.
+    ///
+    /// Junctions that can be written as a [BorderSymbol] for the side
+    /// use [symbol](BorderSymbolSet::symbol), junctions with four arms
+    /// use [crossing](BorderSymbolSet::crossing). Anything else
+    /// falls back to
+    ///
+    /// * the BorderSymbol for another side, if the junction fits there.
+    /// * the box drawing glyph for the arms, if this set uses box
+    ///   drawing glyphs. This is also used if the arms of the
+    ///   border itself have another weight than this set.
+    /// * the nearest T or corner.
+    /// * [line_end](BorderSymbolSet::line_end) for a single arm.
+    ///
+    fn junction(&self, side: Side, junction: Junction) -> &'static str {
+        let weight = |arm: Option<Arm>| {
+            arm.map(|v| Weight::from_border_type(v.border_type))
+                .unwrap_or_default()
+        };
+        let box_drawing = box_glyph::parse(self.symbol(side, BorderSymbol::SideRegular)).is_some();
+        // a box drawing set can't draw its own arms with another weight.
+        let own_fits = !box_drawing
+            || [junction.up, junction.right, junction.down, junction.left]
+                .into_iter()
+                .flatten()
+                .filter(|v| v.side.is_none())
+                .all(|v| weight(Some(v)) == Weight::from_border_type(self.border_type()));
+
+        if own_fits {
+            if let Some(symbol) = junction.to_symbol(side) {
+                return self.symbol(side, symbol);
+            }
+        }
+        if let Junction {
+            up: Some(up),
+            right: Some(right),
+            down: Some(down),
+            left: Some(left),
+        } = junction
+        {
+            return self.crossing(
+                up.side.unwrap_or(side),
+                up.border_type,
+                right.side.unwrap_or(side),
+                right.border_type,
+                down.side.unwrap_or(side),
+                down.border_type,
+                left.side.unwrap_or(side),
+                left.border_type,
+            );
+        }
+        if own_fits {
+            if let Some((side, symbol)) = junction.any_symbol(side) {
+                return self.symbol(side, symbol);
+            }
+        }
+        if box_drawing {
+            return box_glyph::resolve(
+                weight(junction.up),
+                weight(junction.right),
+                weight(junction.down),
+                weight(junction.left),
+            );
+        }
+        if let Some((side, symbol)) = junction.nearest_symbol(side) {
+            return self.symbol(side, symbol);
+        }
+        match junction {
+            Junction { right: Some(_), .. } => self.line_end(Side::Left),
+            Junction { left: Some(_), .. } => self.line_end(Side::Right),
+            Junction { down: Some(_), .. } => self.line_end(Side::Top),
+            Junction { up: Some(_), .. } => self.line_end(Side::Bottom),
+            _ => " ",
+        }
+    }
+
+    ///
+    /// Get the symbol for the end of a single line.
+    ///
+    /// This is used for areas that are only 1 cell high or wide.
+    /// The side gives the end of the line, `Left` and `Right` for
+    /// a horizontal line, `Top` and `Bottom` for a vertical line.
+    ///
+    /// Defaults to the regular line.
+    ///
+    fn line_end(&self, side: Side) -> &'static str {
+        match side {
+            Side::Left | Side::Right => self.symbol(Side::Top, BorderSymbol::SideRegular),
+            Side::Top | Side::Bottom => self.symbol(Side::Left, BorderSymbol::SideRegular),
+        }
+    }
+
+    ///
+    /// Get the symbol for an area of a single cell.
+    ///
+    fn single_cell(&self) -> &'static str {
+        "□"
+    }
 }
 
 /// Symbol descriptor.
@@ -0,0 +1,99 @@
+use crate::box_glyph::{self, Weight};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::widgets::Widget;
+
+///
+/// Connects touching borders that are already in the buffer.
+///
+/// Render this after everything else. It looks at all the box
+/// drawing glyphs in the area, and wherever a line runs into a
+/// glyph that has no arm towards it, the arm is added and the glyph
+/// is replaced with the fitting junction. This way the borders of
+/// plain ratatui Blocks that overlap or touch each other end up
+/// connected, without using [BlockBorder::from_layout](crate::block_border::BlockBorder::from_layout).
+///
+/// Arms are only ever added, the new arm takes the weight of the
+/// line that runs into the glyph. The style of the cells is kept.
+/// Glyphs outside the area are not changed and are not connected to.
+///
+/// The junctions are resolved with [box_glyph::resolve], with the
+/// fallbacks described there. The quadrant and ascii glyphs are
+/// left alone.
+///
+#[derive(Debug, Default, Clone)]
+pub struct MergeBorders;
+
+impl MergeBorders {
+    ///
+    /// New merge pass.
+    ///
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Widget for MergeBorders {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &MergeBorders {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let area = area.intersection(buf.area);
+
+        // Weight of the arm of the neighbour in direction dir
+        // (up, right, down, left) that points back at pos.
+        let neighbour = |buf: &Buffer, pos: Position, dir: usize| -> Weight {
+            let pos = match dir {
+                0 if pos.y > area.top() => Position::new(pos.x, pos.y - 1),
+                1 if pos.x + 1 < area.right() => Position::new(pos.x + 1, pos.y),
+                2 if pos.y + 1 < area.bottom() => Position::new(pos.x, pos.y + 1),
+                3 if pos.x > area.left() => Position::new(pos.x - 1, pos.y),
+                _ => return Weight::None,
+            };
+            buf.cell(pos)
+                .and_then(|v| box_glyph::parse(v.symbol()))
+                .map(|v| v[(dir + 2) % 4].solid())
+                .unwrap_or_default()
+        };
+
+        let mut changes = Vec::new();
+        for pos in area.positions() {
+            let Some(cell) = buf.cell(pos) else {
+                continue;
+            };
+            let Some(mut arms) = box_glyph::parse(cell.symbol()) else {
+                continue;
+            };
+
+            let mut changed = false;
+            for (dir, arm) in arms.iter_mut().enumerate() {
+                if *arm == Weight::None {
+                    let other = neighbour(buf, pos, dir);
+                    if other != Weight::None {
+                        *arm = other;
+                        changed = true;
+                    }
+                }
+            }
+            if changed {
+                let [up, right, down, left] = arms;
+                changes.push((pos, box_glyph::resolve(up, right, down, left)));
+            }
+        }
+
+        for (pos, symbol) in changes {
+            if let Some(cell) = buf.cell_mut(pos) {
+                cell.set_symbol(symbol);
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use crate::block_layout::ConnectedLayout;
+use crate::border_symbols::symbol_set;
+use crate::{BorderSymbol, Side};
+use crossterm::event::Event;
+use rat_event::{ct_event, HandleEvent, MouseOnly, Outcome, Regular};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Direction, Margin, Position, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::{BorderType, StatefulWidget, Widget};
+
+///
+/// Two panes with a shared, movable border between them.
+///
+/// The panes are rendered as a [ConnectedLayout], the shared edge
+/// gets the fitting junctions with the outer border.
+///
+/// The position of the split is kept in the [SplitBorderState].
+/// It can be changed by dragging the shared edge with the mouse,
+/// or with Ctrl+Left/Right (Ctrl+Up/Down for a vertical split)
+/// while [SplitBorderState::focused] is set.
+///
+/// While the mouse hovers over the shared edge or drags it, the edge
+/// is rendered with the hover/drag style, and while dragging with the
+/// drag border type. This shows that the edge can be moved.
+///
+#[derive(Debug, Clone)]
+pub struct SplitBorder {
+    direction: Direction,
+    border_type: BorderType,
+    border_style: Style,
+    hover_style: Option<Style>,
+    drag_style: Option<Style>,
+    drag_border_type: Option<BorderType>,
+}
+
+///
+/// State for [SplitBorder].
+///
+#[derive(Debug, Default, Clone)]
+pub struct SplitBorderState {
+    /// Direction of the split.
+    /// __readonly__. renewed with each render.
+    pub direction: Direction,
+    /// Full area.
+    /// __readonly__. renewed with each render.
+    pub area: Rect,
+    /// Area of the first pane including the border.
+    /// __readonly__. renewed with each render.
+    pub first: Rect,
+    /// Area of the second pane including the border.
+    /// __readonly__. renewed with each render.
+    pub second: Rect,
+    /// The shared edge of both panes.
+    /// __readonly__. renewed with each render.
+    pub divider: Rect,
+    /// The mouse hovers over the shared edge.
+    pub hover: bool,
+    /// The shared edge is being dragged with the mouse.
+    pub dragging: bool,
+    /// The split has the input focus.
+    /// Keyboard events are only handled if this is set.
+    pub focused: bool,
+
+    // position of the shared edge relative to the area.
+    // None splits in the middle.
+    split: Option<u16>,
+}
+
+impl Default for SplitBorder {
+    fn default() -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            border_type: BorderType::Plain,
+            border_style: Default::default(),
+            hover_style: None,
+            drag_style: None,
+            drag_border_type: None,
+        }
+    }
+}
+
+impl SplitBorder {
+    ///
+    /// New split.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Direction of the split.
+    ///
+    /// [Direction::Horizontal] places the panes side by side
+    /// with a vertical edge between them, [Direction::Vertical]
+    /// places them one above the other.
+    ///
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    ///
+    /// Border type for both panes.
+    ///
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    ///
+    /// Border style for both panes.
+    ///
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    ///
+    /// Style for the shared edge while the mouse hovers over it.
+    ///
+    /// This is patched onto the border style.
+    ///
+    pub fn hover_style(mut self, style: Style) -> Self {
+        self.hover_style = Some(style);
+        self
+    }
+
+    ///
+    /// Style for the shared edge while it is dragged.
+    ///
+    /// This is patched onto the border style.
+    /// Defaults to the [hover_style](SplitBorder::hover_style).
+    ///
+    pub fn drag_style(mut self, style: Style) -> Self {
+        self.drag_style = Some(style);
+        self
+    }
+
+    ///
+    /// Border type for the shared edge while it is dragged.
+    ///
+    /// The junctions with the outer border are adjusted too.
+    ///
+    pub fn drag_border_type(mut self, border_type: BorderType) -> Self {
+        self.drag_border_type = Some(border_type);
+        self
+    }
+
+    /// Render the shared edge with the hover or drag visuals.
+    fn render_divider(&self, area: Rect, buf: &mut Buffer, state: &SplitBorderState) {
+        let (style, border_type) = if state.dragging {
+            (
+                self.drag_style.or(self.hover_style),
+                self.drag_border_type.unwrap_or(self.border_type),
+            )
+        } else if state.hover {
+            (self.hover_style, self.border_type)
+        } else {
+            return;
+        };
+
+        let outer = symbol_set(self.border_type);
+        let line = symbol_set(border_type);
+        let (line_side, start, end) = match self.direction {
+            Direction::Horizontal => (Side::Left, Side::Top, Side::Bottom),
+            Direction::Vertical => (Side::Top, Side::Left, Side::Right),
+        };
+
+        let divider = state.divider.intersection(area);
+        let cells = divider.positions().collect::<Vec<_>>();
+        for (n, pos) in cells.iter().enumerate() {
+            let symbol = if n == 0 || n + 1 == cells.len() {
+                let side = if n == 0 { start } else { end };
+                outer.symbol(side, BorderSymbol::SideInward(line_side, border_type))
+            } else {
+                line.symbol(line_side, BorderSymbol::SideRegular)
+            };
+            if let Some(cell) = buf.cell_mut(*pos) {
+                if let Some(style) = style {
+                    cell.set_style(self.border_style.patch(style));
+                }
+                cell.set_symbol(symbol);
+            }
+        }
+    }
+}
+
+impl StatefulWidget for SplitBorder {
+    type State = SplitBorderState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &SplitBorder {
+    type State = SplitBorderState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.direction = self.direction;
+        state.area = area;
+        state.layout();
+
+        ConnectedLayout::new(
+            &[state.first, state.second],
+            &[self.border_type, self.border_type],
+        )
+        .border_style(self.border_style)
+        .render(area, buf);
+
+        self.render_divider(area, buf, state);
+    }
+}
+
+impl SplitBorderState {
+    ///
+    /// New state.
+    ///
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Position of the shared edge relative to the area.
+    ///
+    pub fn split(&self) -> u16 {
+        self.split.unwrap_or(self.len() / 2)
+    }
+
+    ///
+    /// Set the position of the shared edge relative to the area.
+    ///
+    /// The position is limited so that both panes keep at least
+    /// their border. Returns true if the position changed.
+    ///
+    pub fn set_split(&mut self, split: u16) -> bool {
+        let old = self.split();
+        self.split = Some(split);
+        self.layout();
+        old != self.split()
+    }
+
+    ///
+    /// Inner area of the first pane without the border.
+    ///
+    pub fn first_inner(&self) -> Rect {
+        self.first.inner(Margin::new(1, 1))
+    }
+
+    ///
+    /// Inner area of the second pane without the border.
+    ///
+    pub fn second_inner(&self) -> Rect {
+        self.second.inner(Margin::new(1, 1))
+    }
+
+    /// Length of the area in the direction of the split.
+    fn len(&self) -> u16 {
+        match self.direction {
+            Direction::Horizontal => self.area.width,
+            Direction::Vertical => self.area.height,
+        }
+    }
+
+    /// Clamp the split and calculate the areas.
+    fn layout(&mut self) {
+        if self.area.is_empty() {
+            self.first = Rect::default();
+            self.second = Rect::default();
+            self.divider = Rect::default();
+            return;
+        }
+
+        if let Some(split) = self.split {
+            let max = self.len().saturating_sub(2).max(1);
+            self.split = Some(split.clamp(1.min(max), max));
+        }
+        let split = self.split();
+
+        let area = self.area;
+        match self.direction {
+            Direction::Horizontal => {
+                self.first = Rect::new(area.x, area.y, split + 1, area.height);
+                self.second = Rect::new(area.x + split, area.y, area.width - split, area.height);
+                self.divider = Rect::new(area.x + split, area.y, 1, area.height);
+            }
+            Direction::Vertical => {
+                self.first = Rect::new(area.x, area.y, area.width, split + 1);
+                self.second = Rect::new(area.x, area.y + split, area.width, area.height - split);
+                self.divider = Rect::new(area.x, area.y + split, area.width, 1);
+            }
+        }
+    }
+
+    /// Is the position on the shared edge. This includes the
+    /// junctions with the outer border at both ends.
+    fn on_divider(&self, position: Position) -> bool {
+        self.divider.contains(position)
+    }
+
+    /// Move the split to the mouse position.
+    fn drag_to(&mut self, x: u16, y: u16) -> Outcome {
+        let split = match self.direction {
+            Direction::Horizontal => x.saturating_sub(self.area.x),
+            Direction::Vertical => y.saturating_sub(self.area.y),
+        };
+        self.set_split(split).into()
+    }
+}
+
+impl HandleEvent<Event, Regular, Outcome> for SplitBorderState {
+    fn handle(&mut self, event: &Event, _qualifier: Regular) -> Outcome {
+        let r = match (self.direction, event) {
+            _ if !self.focused => Outcome::Continue,
+            (Direction::Horizontal, ct_event!(keycode press CONTROL-Left))
+            | (Direction::Vertical, ct_event!(keycode press CONTROL-Up)) => {
+                self.set_split(self.split().saturating_sub(1)).into()
+            }
+            (Direction::Horizontal, ct_event!(keycode press CONTROL-Right))
+            | (Direction::Vertical, ct_event!(keycode press CONTROL-Down)) => {
+                self.set_split(self.split().saturating_add(1)).into()
+            }
+            _ => Outcome::Continue,
+        };
+
+        if r == Outcome::Continue {
+            self.handle(event, MouseOnly)
+        } else {
+            r
+        }
+    }
+}
+
+impl HandleEvent<Event, MouseOnly, Outcome> for SplitBorderState {
+    fn handle(&mut self, event: &Event, _qualifier: MouseOnly) -> Outcome {
+        match event {
+            ct_event!(mouse moved for x, y) => {
+                let hover = self.on_divider(Position::new(*x, *y));
+                if hover != self.hover {
+                    self.hover = hover;
+                    Outcome::Changed
+                } else {
+                    Outcome::Continue
+                }
+            }
+            ct_event!(mouse down Left for x, y) if self.on_divider(Position::new(*x, *y)) => {
+                self.dragging = true;
+                Outcome::Changed
+            }
+            ct_event!(mouse drag Left for x, y) if self.dragging => self.drag_to(*x, *y),
+            ct_event!(mouse up Left for x, y) if self.dragging => {
+                self.dragging = false;
+                self.hover = self.on_divider(Position::new(*x, *y));
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        }
+    }
+}
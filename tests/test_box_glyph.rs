@@ -0,0 +1,167 @@
+use ratatui::widgets::BorderType;
+use ratatui_block::border_symbols::{DashPattern, DoubleSymbolSet, PlainSymbolSet, ThickSymbolSet};
+use ratatui_block::box_glyph::{parse, resolve, Weight};
+use ratatui_block::junction::Junction;
+use ratatui_block::{BorderSymbol, BorderSymbolSet, Side};
+
+/// Arms of a symbol of a border with the given type.
+fn arms(side: Side, symbol: BorderSymbol, own: BorderType) -> [Weight; 4] {
+    let junction = Junction::from_symbol(side, symbol, own);
+    [junction.up, junction.right, junction.down, junction.left].map(|v| {
+        v.map(|v| Weight::from_border_type(v.border_type))
+            .unwrap_or_default()
+    })
+}
+
+/// All symbols with connections to the given border types.
+fn symbols(types: &[BorderType]) -> Vec<BorderSymbol> {
+    let s = Side::Top;
+    let mut symbols = vec![
+        BorderSymbol::StartCornerRegular,
+        BorderSymbol::SideRegular,
+        BorderSymbol::EndCornerRegular,
+    ];
+    for a in types.iter().copied() {
+        symbols.push(BorderSymbol::StartCornerAngled(s, a));
+        symbols.push(BorderSymbol::StartCornerProlonged(s, a));
+        symbols.push(BorderSymbol::SideOverlap(s, a));
+        symbols.push(BorderSymbol::SideOutward(s, a));
+        symbols.push(BorderSymbol::SideInward(s, a));
+        symbols.push(BorderSymbol::EndCornerAngled(s, a));
+        symbols.push(BorderSymbol::EndCornerProlonged(s, a));
+        for b in types.iter().copied() {
+            symbols.push(BorderSymbol::StartCornerCrossed(s, a, s, b));
+            symbols.push(BorderSymbol::SideCrossed(s, a, s, b));
+            symbols.push(BorderSymbol::EndCornerCrossed(s, a, s, b));
+        }
+    }
+    symbols
+}
+
+fn verify(set: &dyn BorderSymbolSet, types: &[BorderType]) {
+    let mut failed = Vec::new();
+    for side in [Side::Top, Side::Right, Side::Bottom, Side::Left] {
+        for symbol in symbols(types) {
+            let [up, right, down, left] = arms(side, symbol, set.border_type());
+            let expected = resolve(up, right, down, left);
+            let actual = set.symbol(side, symbol);
+            if expected != actual {
+                failed.push(format!(
+                    "{:?} {:?}: {} != {}",
+                    side, symbol, actual, expected
+                ));
+            }
+        }
+    }
+    assert!(failed.is_empty(), "{:#?}", failed);
+}
+
+#[test]
+fn resolve_glyphs() {
+    use Weight::*;
+    assert_eq!(resolve(None, None, None, None), " ");
+    assert_eq!(resolve(Light, Light, Light, Light), "┼");
+    assert_eq!(resolve(Heavy, Light, Heavy, Light), "╂");
+    assert_eq!(resolve(None, Heavy, Light, None), "┍");
+    assert_eq!(resolve(None, Light, None, Heavy), "╾");
+    assert_eq!(resolve(Light, None, None, None), "╵");
+    assert_eq!(resolve(None, Double, Light, None), "╒");
+    assert_eq!(resolve(Double, Double, Double, Double), "╬");
+}
+
+#[test]
+fn resolve_fallbacks() {
+    use Weight::*;
+    // minority on the mixed axis.
+    assert_eq!(resolve(Double, Light, Light, None), "╟");
+    assert_eq!(resolve(Light, Double, Double, None), "╞");
+    // tie
+    assert_eq!(resolve(Double, Light, Light, Double), "╬");
+    // heavy and double
+    assert_eq!(resolve(Heavy, Double, None, Double), "╩");
+    // single double arm
+    assert_eq!(resolve(None, None, Double, None), "║");
+    // dashes
+    assert_eq!(
+        resolve(
+            None,
+            LightDashed(DashPattern::Triple),
+            None,
+            LightDashed(DashPattern::Triple)
+        ),
+        "┄"
+    );
+    assert_eq!(
+        resolve(
+            HeavyDashed(DashPattern::Double),
+            None,
+            HeavyDashed(DashPattern::Double),
+            None
+        ),
+        "╏"
+    );
+    assert_eq!(
+        resolve(
+            LightDashed(DashPattern::Double),
+            Light,
+            LightDashed(DashPattern::Double),
+            None
+        ),
+        "├"
+    );
+}
+
+#[test]
+fn verify_plain() {
+    verify(&PlainSymbolSet, &[BorderType::Plain, BorderType::Thick]);
+    verify(&PlainSymbolSet, &[BorderType::Plain, BorderType::Double]);
+}
+
+#[test]
+fn verify_thick() {
+    verify(&ThickSymbolSet, &[BorderType::Plain, BorderType::Thick]);
+}
+
+#[test]
+fn verify_double() {
+    verify(&DoubleSymbolSet, &[BorderType::Plain, BorderType::Double]);
+}
+
+#[test]
+fn parse_glyphs() {
+    use Weight::*;
+    assert_eq!(parse("╞"), Some([Light, Double, Light, None]));
+    assert_eq!(parse("┿"), Some([Light, Heavy, Light, Heavy]));
+    assert_eq!(parse("╯"), Some([Light, None, None, Light]));
+    assert_eq!(
+        parse("┆"),
+        Some([
+            LightDashed(DashPattern::Triple),
+            None,
+            LightDashed(DashPattern::Triple),
+            None
+        ])
+    );
+    assert_eq!(parse("╳"), Option::None);
+    assert_eq!(parse("x"), Option::None);
+    assert_eq!(parse(" "), Option::None);
+    assert_eq!(parse(""), Option::None);
+}
+
+#[test]
+fn parse_block() {
+    for c in '\u{2500}'..='\u{257F}' {
+        let glyph = c.to_string();
+        if matches!(c, '╱' | '╲' | '╳') {
+            assert_eq!(parse(&glyph), Option::None);
+            continue;
+        }
+        let Some([up, right, down, left]) = parse(&glyph) else {
+            panic!("no arms for {}", glyph);
+        };
+        if matches!(c, '╭' | '╮' | '╯' | '╰') {
+            continue;
+        }
+        assert_eq!(resolve(up, right, down, left), glyph);
+    }
+}
@@ -0,0 +1,49 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_border::BlockBorder;
+use ratatui_block::border_symbols::{DashPattern, DashedSymbolSet, ThickDashedSymbolSet};
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn dashed_patterns() {
+    for (pattern, expect) in [
+        (DashPattern::Double, vec!["┌╌╌┐", "╎  ╎", "└╌╌┘"]),
+        (DashPattern::Triple, vec!["┌┄┄┐", "┆  ┆", "└┄┄┘"]),
+        (DashPattern::Quadruple, vec!["┌┈┈┐", "┊  ┊", "└┈┈┘"]),
+    ] {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        BlockBorder::new()
+            .border_set(Box::new(DashedSymbolSet::new(pattern)))
+            .render(buf.area, &mut buf);
+        assert_eq!(rows(&buf), expect);
+    }
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+    BlockBorder::new()
+        .border_set(Box::new(ThickDashedSymbolSet::new(DashPattern::Triple)))
+        .render(buf.area, &mut buf);
+    assert_eq!(rows(&buf), vec!["┏┅┅┓", "┇  ┇", "┗┅┅┛"]);
+}
+
+#[test]
+fn dashed_junctions() {
+    let areas = [Rect::new(0, 0, 5, 3), Rect::new(4, 0, 5, 3)];
+    let borders = [BorderType::Plain, BorderType::Plain];
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+    BlockBorder::from_layout(&areas, &borders, 1).render(areas[1], &mut buf);
+    BlockBorder::from_layout(&areas, &borders, 0)
+        .border_set(Box::new(DashedSymbolSet::new(DashPattern::Double)))
+        .render(areas[0], &mut buf);
+    assert_eq!(rows(&buf), vec!["┌╌╌╌┬───┐", "╎   ╎   │", "└╌╌╌┴───┘"]);
+}
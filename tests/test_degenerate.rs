@@ -0,0 +1,270 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::symbols::border;
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_border::BlockBorder;
+use ratatui_block::block_layout::ConnectedLayout;
+use ratatui_block::border_symbols::{
+    AsciiSymbolSet, DashPattern, DashedSymbolSet, DoubleSymbolSet, OldSymbolSet, PlainSymbolSet,
+    QuadrantInsideSymbolSet, QuadrantOutsideSymbolSet, RoundedSymbolSet, StarSymbolSet,
+    ThickDashedSymbolSet, ThickSymbolSet,
+};
+use ratatui_block::{BorderSymbol, BorderSymbolSet, Side};
+
+fn symbol_sets() -> Vec<Box<dyn BorderSymbolSet>> {
+    vec![
+        Box::new(PlainSymbolSet),
+        Box::new(RoundedSymbolSet),
+        Box::new(DoubleSymbolSet),
+        Box::new(ThickSymbolSet),
+        Box::new(QuadrantInsideSymbolSet),
+        Box::new(QuadrantOutsideSymbolSet),
+        Box::new(AsciiSymbolSet),
+        Box::new(StarSymbolSet),
+        Box::new(DashedSymbolSet::new(DashPattern::Triple)),
+        Box::new(ThickDashedSymbolSet::new(DashPattern::Double)),
+        Box::new(OldSymbolSet {
+            border_type: BorderType::Plain,
+            symbol_set: border::PLAIN,
+        }),
+    ]
+}
+
+fn content(buf: &Buffer) -> String {
+    buf.content.iter().map(|v| v.symbol()).collect()
+}
+
+// render once without and once with a prefabricated border.
+fn render(set: &(dyn BorderSymbolSet + 'static), area: Rect) -> [Buffer; 2] {
+    let buf_area = Rect::new(0, 0, 7, 7);
+
+    let mut direct = Buffer::empty(buf_area);
+    BlockBorder::new()
+        .border_set(dyn_clone::clone_box(set))
+        .render(area, &mut direct);
+
+    let mut prefab = Buffer::empty(buf_area);
+    let mut block = BlockBorder::new().border_set(dyn_clone::clone_box(set));
+    if !area.is_empty() {
+        block.set_symbol(area, area.as_position(), BorderSymbol::StartCornerRegular);
+    }
+    block.render(area, &mut prefab);
+
+    [direct, prefab]
+}
+
+#[test]
+fn empty_area() {
+    for set in symbol_sets() {
+        for area in [
+            Rect::new(1, 1, 0, 0),
+            Rect::new(1, 1, 5, 0),
+            Rect::new(1, 1, 0, 5),
+        ] {
+            for buf in render(set.as_ref(), area) {
+                assert_eq!(content(&buf), " ".repeat(49));
+            }
+        }
+    }
+}
+
+#[test]
+fn single_cell() {
+    for set in symbol_sets() {
+        for buf in render(set.as_ref(), Rect::new(1, 1, 1, 1)) {
+            assert_eq!(buf[(1, 1)].symbol(), set.single_cell());
+            assert_eq!(content(&buf).trim(), set.single_cell());
+        }
+    }
+}
+
+#[test]
+fn horizontal_line() {
+    for set in symbol_sets() {
+        let line = set.symbol(Side::Top, BorderSymbol::SideRegular);
+        let expect = format!(
+            "{}{}{}",
+            set.line_end(Side::Left),
+            line.repeat(3),
+            set.line_end(Side::Right)
+        );
+        for buf in render(set.as_ref(), Rect::new(1, 1, 5, 1)) {
+            let row = (1..6).map(|x| buf[(x, 1)].symbol()).collect::<String>();
+            assert_eq!(row, expect);
+            assert_eq!(buf[(1, 2)].symbol(), " ");
+        }
+    }
+}
+
+#[test]
+fn vertical_line() {
+    for set in symbol_sets() {
+        let line = set.symbol(Side::Left, BorderSymbol::SideRegular);
+        let expect = format!(
+            "{}{}{}",
+            set.line_end(Side::Top),
+            line.repeat(3),
+            set.line_end(Side::Bottom)
+        );
+        for buf in render(set.as_ref(), Rect::new(1, 1, 1, 5)) {
+            let column = (1..6).map(|y| buf[(1, y)].symbol()).collect::<String>();
+            assert_eq!(column, expect);
+            assert_eq!(buf[(2, 1)].symbol(), " ");
+        }
+    }
+}
+
+#[test]
+fn line_glyphs() {
+    let [buf, _] = render(&PlainSymbolSet, Rect::new(0, 0, 4, 1));
+    assert_eq!(&content(&buf)[..12], "╶──╴");
+    let [buf, _] = render(&ThickSymbolSet, Rect::new(0, 0, 1, 3));
+    let column = (0..3).map(|y| buf[(0, y)].symbol()).collect::<String>();
+    assert_eq!(column, "╻┃╹");
+    let [buf, _] = render(&DoubleSymbolSet, Rect::new(0, 0, 3, 1));
+    assert_eq!(&content(&buf)[..9], "═══");
+}
+
+#[test]
+fn two_by_two() {
+    for set in symbol_sets() {
+        for buf in render(set.as_ref(), Rect::new(1, 1, 2, 2)) {
+            assert_eq!(
+                buf[(1, 1)].symbol(),
+                set.symbol(Side::Top, BorderSymbol::StartCornerRegular)
+            );
+            assert_eq!(
+                buf[(2, 2)].symbol(),
+                set.symbol(Side::Bottom, BorderSymbol::EndCornerRegular)
+            );
+        }
+    }
+}
+
+#[test]
+fn prefab_degenerate() {
+    let area = Rect::new(0, 0, 1, 3);
+    let mut block = BlockBorder::new();
+    block.set_symbol(area, Position::new(0, 1), BorderSymbol::StartCornerRegular);
+    assert_eq!(
+        block.get_symbol(area, Position::new(0, 1)),
+        BorderSymbol::StartCornerRegular
+    );
+    assert_eq!(
+        block.get_symbol(area, Position::new(0, 2)),
+        BorderSymbol::StartCornerRegular
+    );
+
+    // rendered row, column and single cell.
+    let sets: [(Box<dyn BorderSymbolSet>, [&str; 3]); 10] = [
+        (Box::new(PlainSymbolSet), ["╶──╴", "╷││╵", "□"]),
+        (Box::new(RoundedSymbolSet), ["╶──╴", "╷││╵", "□"]),
+        (Box::new(DoubleSymbolSet), ["════", "║║║║", "□"]),
+        (Box::new(ThickSymbolSet), ["╺━━╸", "╻┃┃╹", "■"]),
+        (Box::new(QuadrantInsideSymbolSet), ["▄▄▄▄", "▐▐▐▐", "█"]),
+        (Box::new(QuadrantOutsideSymbolSet), ["▀▀▀▀", "▌▌▌▌", "█"]),
+        (Box::new(AsciiSymbolSet), ["+--+", "+||+", "+"]),
+        (Box::new(StarSymbolSet), ["****", "****", "*"]),
+        (
+            Box::new(DashedSymbolSet::new(DashPattern::Triple)),
+            ["╶┄┄╴", "╷┆┆╵", "□"],
+        ),
+        (
+            Box::new(ThickDashedSymbolSet::new(DashPattern::Double)),
+            ["╺╍╍╸", "╻╏╏╹", "■"],
+        ),
+    ];
+    for (set, expect) in sets {
+        for ((area, position), expect) in [
+            (Rect::new(0, 0, 4, 1), Position::new(1, 0)),
+            (Rect::new(0, 0, 1, 4), Position::new(0, 1)),
+            (Rect::new(0, 0, 1, 1), Position::new(0, 0)),
+        ]
+        .into_iter()
+        .zip(expect)
+        {
+            let mut block = BlockBorder::new().border_set(dyn_clone::clone_box(set.as_ref()));
+            block.set_symbol(area, position, BorderSymbol::SideRegular);
+            let mut buf = Buffer::empty(area);
+            block.render(area, &mut buf);
+            assert_eq!(content(&buf), expect);
+        }
+    }
+}
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn layout_degenerate() {
+    let lines = [
+        // 1×N above the pane, ending on its corner.
+        [Rect::new(0, 0, 1, 5), Rect::new(0, 4, 5, 3)],
+        // 1×N above the pane, ending on its side.
+        [Rect::new(2, 0, 1, 5), Rect::new(0, 4, 5, 3)],
+        // 0×N, renders nothing.
+        [Rect::new(0, 0, 0, 5), Rect::new(0, 4, 5, 3)],
+    ];
+    let expect = [
+        vec![
+            "╷    ",
+            "│    ",
+            "│    ",
+            "│    ",
+            "├───┐",
+            "│   │",
+            "└───┘",
+        ],
+        vec![
+            "  ╷  ",
+            "  │  ",
+            "  │  ",
+            "  │  ",
+            "┌─┴─┐",
+            "│   │",
+            "└───┘",
+        ],
+        vec![
+            "     ",
+            "     ",
+            "     ",
+            "     ",
+            "┌───┐",
+            "│   │",
+            "└───┘",
+        ],
+    ];
+
+    for (areas, expect) in lines.iter().zip(expect) {
+        let borders = [BorderType::Plain; 2];
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 7));
+        for n in 0..areas.len() {
+            BlockBorder::from_layout(areas, &borders, n).render(areas[n], &mut buf);
+        }
+        assert_eq!(rows(&buf), expect);
+
+        // ConnectedLayout lets the first area win, render
+        // the pane first.
+        let areas = [areas[1], areas[0]];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 7));
+        ConnectedLayout::new(&areas, &borders).render(buf.area, &mut buf);
+        assert_eq!(rows(&buf), expect);
+    }
+}
+
+#[test]
+fn layout_degenerate_horizontal() {
+    let areas = [Rect::new(0, 0, 4, 4), Rect::new(3, 2, 4, 1)];
+    let borders = [BorderType::Plain; 2];
+    let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+    ConnectedLayout::new(&areas, &borders).render(buf.area, &mut buf);
+    assert_eq!(rows(&buf), vec!["┌──┐   ", "│  │   ", "│  ├──╴", "└──┘   "]);
+}
@@ -0,0 +1,312 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_grid::BlockGrid;
+use ratatui_block::border_symbols::{DoubleSymbolSet, ThickSymbolSet};
+use ratatui_block::Side;
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+fn grid() -> BlockGrid {
+    BlockGrid::new()
+        .vertical(3)
+        .vertical(6)
+        .horizontal(2)
+        .horizontal(4)
+}
+
+#[test]
+fn grid_plain() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    grid().render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──┬──┬──┐",
+            "│  │  │  │",
+            "├──┼──┼──┤",
+            "│  │  │  │",
+            "├──┼──┼──┤",
+            "│  │  │  │",
+            "└──┴──┴──┘",
+        ]
+    );
+}
+
+#[test]
+fn grid_quadrant_sides() {
+    let quadrant = || {
+        grid()
+            .horizontal_border_type(BorderType::QuadrantInside)
+            .vertical_border_type(BorderType::QuadrantInside)
+    };
+
+    // without a side the lines use the top and left variant.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    quadrant().render(buf.area, &mut buf);
+    let mut expected = Buffer::empty(buf.area);
+    quadrant()
+        .horizontal_side(Side::Top)
+        .vertical_side(Side::Left)
+        .render(expected.area, &mut expected);
+    assert_eq!(buf, expected);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──┬──┬──┐",
+            "│  ▐  ▐  │",
+            "├▄▄▟▄▄▟▄▄┤",
+            "│  ▐  ▐  │",
+            "├▄▄▟▄▄▟▄▄┤",
+            "│  ▐  ▐  │",
+            "└──┴──┴──┘",
+        ]
+    );
+
+    // a side that is set is used as is.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    quadrant()
+        .horizontal_side(Side::Bottom)
+        .vertical_side(Side::Right)
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──┬──┬──┐",
+            "│  ▌  ▌  │",
+            "├▀▀▛▀▀▛▀▀┤",
+            "│  ▌  ▌  │",
+            "├▀▀▛▀▀▛▀▀┤",
+            "│  ▌  ▌  │",
+            "└──┴──┴──┘",
+        ]
+    );
+}
+
+#[test]
+fn grid_span_columns() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    grid()
+        .span(Rect::new(0, 0, 2, 1))
+        .span(Rect::new(1, 1, 2, 2))
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌─────┬──┐",
+            "│     │  │",
+            "├──┬──┴──┤",
+            "│  │     │",
+            "├──┤     │",
+            "│  │     │",
+            "└──┴─────┘",
+        ]
+    );
+}
+
+#[test]
+fn grid_span_rows() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    grid()
+        .span(Rect::new(0, 0, 1, 3))
+        .span(Rect::new(1, 0, 2, 2))
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──┬─────┐",
+            "│  │     │",
+            "│  │     │",
+            "│  │     │",
+            "│  ├──┬──┤",
+            "│  │  │  │",
+            "└──┴──┴──┘",
+        ]
+    );
+}
+
+#[test]
+fn nested_layout() {
+    // +----+---+
+    // |    |   |
+    // +-+--+   |
+    // | |  +---+
+    // | |  |   |
+    // +-+--+---+
+    let areas = [
+        Rect::new(11, 21, 4, 1),
+        Rect::new(11, 23, 1, 2),
+        Rect::new(13, 23, 2, 2),
+        Rect::new(16, 21, 3, 2),
+        Rect::new(16, 24, 3, 1),
+    ];
+    let grid = BlockGrid::from_nested_layout(&areas);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+    (&grid).render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌────┬───┐",
+            "│    │   │",
+            "├─┬──┤   │",
+            "│ │  ├───┤",
+            "│ │  │   │",
+            "└─┴──┴───┘",
+        ]
+    );
+
+    // clipped
+    let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+    (&grid).render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌────┬┐", //
+            "│    ││",
+            "├─┬──┤│",
+            "└─┴──┴┘",
+        ]
+    );
+}
+
+#[test]
+fn nested_layout_shape() {
+    // only the outline of the areas is drawn.
+    let areas = [Rect::new(1, 1, 4, 3), Rect::new(1, 5, 10, 3)];
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 9));
+    BlockGrid::from_nested_layout(&areas).render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌────┐      ",
+            "│    │      ",
+            "│    │      ",
+            "│    │      ",
+            "├────┴─────┐",
+            "│          │",
+            "│          │",
+            "│          │",
+            "└──────────┘",
+        ]
+    );
+
+    // the edges use the outer border.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 9));
+    BlockGrid::from_nested_layout(&areas)
+        .border_type(BorderType::Double)
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "╔════╗      ",
+            "║    ║      ",
+            "║    ║      ",
+            "║    ║      ",
+            "╟────╩═════╗",
+            "║          ║",
+            "║          ║",
+            "║          ║",
+            "╚══════════╝",
+        ]
+    );
+}
+
+#[test]
+fn nested_layout_edge() {
+    // the first area has no room for a border.
+    let areas = [Rect::new(0, 1, 4, 3), Rect::new(1, 5, 10, 3)];
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 5));
+    BlockGrid::from_nested_layout(&areas).render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──────────┐",
+            "│          │",
+            "│          │",
+            "│          │",
+            "└──────────┘",
+        ]
+    );
+}
+
+#[test]
+fn grid_segment_corners() {
+    // the corners use the types of both lines.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 8));
+    BlockGrid::new()
+        .horizontal_border_type(BorderType::Double)
+        .horizontal_segment(3, 2, 6)
+        .vertical_segment(6, 3, 5)
+        .horizontal_segment(5, 2, 6)
+        .vertical_segment(2, 1, 3)
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌────────┐",
+            "│ ╷      │",
+            "│ │      │",
+            "│ ╘═══╕  │",
+            "│     │  │",
+            "│ ════╛  │",
+            "│        │",
+            "└────────┘",
+        ]
+    );
+}
+
+#[test]
+fn grid_custom_lines() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    BlockGrid::new()
+        .vertical(3)
+        .vertical_line(6, DoubleSymbolSet, Style::new())
+        .horizontal_line(2, ThickSymbolSet, Style::new())
+        .horizontal(4)
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──┬──╥──┐",
+            "│  │  ║  │",
+            "┝━━┿━━╋━━┥",
+            "│  │  ║  │",
+            "├──┼──╫──┤",
+            "│  │  ║  │",
+            "└──┴──╨──┘",
+        ]
+    );
+}
+
+#[test]
+fn grid_crossing_style() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    grid()
+        .horizontal_style(Style::new().blue())
+        .crossing_style(Style::new().yellow())
+        .render(buf.area, &mut buf);
+    assert_eq!(buf[(3, 2)].symbol(), "┼");
+    assert_eq!(buf[(3, 2)].fg, Color::Yellow);
+    assert_eq!(buf[(6, 4)].fg, Color::Yellow);
+    assert_eq!(buf[(4, 2)].fg, Color::Blue);
+}
+
+#[test]
+fn grid_crossing_inherits_style() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 7));
+    grid()
+        .horizontal_style(Style::new().blue())
+        .render(buf.area, &mut buf);
+    assert_eq!(buf[(3, 2)].fg, Color::Blue);
+    assert_eq!(buf[(6, 4)].fg, Color::Blue);
+}
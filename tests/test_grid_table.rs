@@ -0,0 +1,57 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::grid_table::GridTable;
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn table_header() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+    GridTable::new([["a", "bb", "c"], ["dd", "e", "f"]], [])
+        .header(["A", "B", "C"])
+        .header_separator(BorderType::Double)
+        .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌──┬──┬────┐",
+            "│A │B │C   │",
+            "╞══╪══╪════╡",
+            "│a │bb│c   │",
+            "│dd│e │f   │",
+            "└──┴──┴────┘",
+        ]
+    );
+}
+
+#[test]
+fn table_row_separators() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 7));
+    GridTable::new(
+        [["a", "b"], ["c", "d"], ["e", "f"]],
+        [Constraint::Length(3), Constraint::Fill(1)],
+    )
+    .row_separators(true)
+    .render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌───┬───┐",
+            "│a  │b  │",
+            "├───┼───┤",
+            "│c  │d  │",
+            "├───┼───┤",
+            "│e  │f  │",
+            "└───┴───┘",
+        ]
+    );
+}
@@ -0,0 +1,147 @@
+use ratatui::layout::{Position, Rect};
+use ratatui::widgets::BorderType;
+use ratatui_block::block_border::{BlockBorder, BorderHit};
+use ratatui_block::block_grid::{BlockGrid, GridHit};
+use ratatui_block::block_layout::{ConnectedLayout, LayoutHit};
+use ratatui_block::{BorderSymbol, Side};
+
+fn areas() -> [Rect; 3] {
+    [
+        Rect::new(0, 0, 6, 4),
+        Rect::new(5, 0, 5, 4),
+        Rect::new(0, 3, 10, 3),
+    ]
+}
+
+#[test]
+fn hit_border() {
+    let areas = areas();
+    let borders = [BorderType::Plain; 3];
+    let border = BlockBorder::from_layout(&areas, &borders, 0);
+
+    assert_eq!(border.hit(areas[0], Position::new(7, 0)), None);
+    assert_eq!(
+        border.hit(areas[0], Position::new(2, 1)),
+        Some(BorderHit::Inside)
+    );
+    assert_eq!(
+        border.hit(areas[0], Position::new(0, 0)),
+        Some(BorderHit::Border(
+            Side::Top,
+            BorderSymbol::StartCornerRegular
+        ))
+    );
+    assert_eq!(
+        border.hit(areas[0], Position::new(2, 0)),
+        Some(BorderHit::Border(Side::Top, BorderSymbol::SideRegular))
+    );
+    assert_eq!(
+        border.hit(areas[0], Position::new(5, 1)),
+        Some(BorderHit::Border(Side::Right, BorderSymbol::SideRegular))
+    );
+    assert!(matches!(
+        border.hit(areas[0], Position::new(5, 3)),
+        Some(BorderHit::Border(Side::Bottom, _))
+    ));
+}
+
+#[test]
+fn hit_layout() {
+    let areas = areas();
+    let layout = ConnectedLayout::new(&areas, &[BorderType::Plain; 3]);
+
+    assert_eq!(layout.hit(Position::new(20, 20)), None);
+    assert_eq!(layout.hit(Position::new(2, 1)), Some(LayoutHit::Inside(0)));
+    assert_eq!(layout.hit(Position::new(7, 4)), Some(LayoutHit::Inside(2)));
+    assert_eq!(
+        layout.hit(Position::new(2, 0)),
+        Some(LayoutHit::Edge {
+            areas: vec![0],
+            side: Side::Top,
+            symbol: BorderSymbol::SideRegular
+        })
+    );
+    assert!(matches!(
+        layout.hit(Position::new(5, 1)),
+        Some(LayoutHit::Edge { areas, side: Side::Right, .. }) if areas == vec![0, 1]
+    ));
+    assert!(matches!(
+        layout.hit(Position::new(5, 0)),
+        Some(LayoutHit::Junction { areas, side: Side::Top, .. }) if areas == vec![0, 1]
+    ));
+    assert!(matches!(
+        layout.hit(Position::new(5, 3)),
+        Some(LayoutHit::Junction { areas, .. }) if areas == vec![0, 1, 2]
+    ));
+}
+
+#[test]
+fn hit_grid() {
+    let area = Rect::new(0, 0, 10, 7);
+    let grid = BlockGrid::new()
+        .vertical(3)
+        .vertical(6)
+        .horizontal(2)
+        .horizontal(4)
+        .span(Rect::new(1, 1, 2, 2));
+
+    assert_eq!(grid.hit(area, Position::new(10, 0)), None);
+    assert_eq!(
+        grid.hit(area, Position::new(0, 0)),
+        Some(GridHit::Border(Side::Top, BorderSymbol::StartCornerRegular))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(3, 0)),
+        Some(GridHit::Border(
+            Side::Top,
+            BorderSymbol::SideInward(Side::Left, BorderType::Plain)
+        ))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(9, 1)),
+        Some(GridHit::Border(Side::Right, BorderSymbol::SideRegular))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(1, 2)),
+        Some(GridHit::Horizontal(2))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(3, 5)),
+        Some(GridHit::Vertical(3))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(3, 2)),
+        Some(GridHit::Junction(3, 2))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(8, 5)),
+        Some(GridHit::Cell(2, 2))
+    );
+    // inside the span.
+    assert_eq!(
+        grid.hit(area, Position::new(6, 4)),
+        Some(GridHit::Cell(1, 1))
+    );
+}
+
+#[test]
+fn hit_nested_grid() {
+    let area = Rect::new(0, 0, 13, 10);
+    let grid = BlockGrid::from_nested_layout(&[Rect::new(1, 1, 5, 3), Rect::new(7, 1, 5, 8)]);
+
+    // the gap below the first area.
+    assert_eq!(grid.hit(area, Position::new(0, 7)), None);
+    assert_eq!(grid.hit(area, Position::new(3, 7)), None);
+    assert_eq!(
+        grid.hit(area, Position::new(0, 2)),
+        Some(GridHit::Vertical(0))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(6, 4)),
+        Some(GridHit::Junction(6, 4))
+    );
+    assert_eq!(
+        grid.hit(area, Position::new(3, 2)),
+        Some(GridHit::Cell(0, 0))
+    );
+}
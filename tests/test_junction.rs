@@ -0,0 +1,305 @@
+use ratatui::widgets::BorderType;
+use ratatui_block::border_symbols::{symbol_set, AsciiSymbolSet};
+use ratatui_block::junction::{Arm, Junction};
+use ratatui_block::{BorderSymbol, BorderSymbolSet, Side};
+
+const SIDES: [Side; 4] = [Side::Top, Side::Right, Side::Bottom, Side::Left];
+const TYPES: [BorderType; 6] = [
+    BorderType::Plain,
+    BorderType::Rounded,
+    BorderType::Double,
+    BorderType::Thick,
+    BorderType::QuadrantInside,
+    BorderType::QuadrantOutside,
+];
+
+/// All symbols with all sides and border types.
+fn symbols() -> Vec<BorderSymbol> {
+    let mut symbols = vec![
+        BorderSymbol::StartCornerRegular,
+        BorderSymbol::SideRegular,
+        BorderSymbol::EndCornerRegular,
+    ];
+    for s0 in SIDES {
+        for b0 in TYPES {
+            symbols.push(BorderSymbol::StartCornerAngled(s0, b0));
+            symbols.push(BorderSymbol::StartCornerProlonged(s0, b0));
+            symbols.push(BorderSymbol::SideOverlap(s0, b0));
+            symbols.push(BorderSymbol::SideOutward(s0, b0));
+            symbols.push(BorderSymbol::SideInward(s0, b0));
+            symbols.push(BorderSymbol::EndCornerAngled(s0, b0));
+            symbols.push(BorderSymbol::EndCornerProlonged(s0, b0));
+            for s1 in SIDES {
+                for b1 in TYPES {
+                    symbols.push(BorderSymbol::StartCornerCrossed(s0, b0, s1, b1));
+                    symbols.push(BorderSymbol::SideCrossed(s0, b0, s1, b1));
+                    symbols.push(BorderSymbol::EndCornerCrossed(s0, b0, s1, b1));
+                }
+            }
+        }
+    }
+    symbols
+}
+
+#[test]
+fn junction_roundtrip() {
+    for side in SIDES {
+        for own in TYPES {
+            for symbol in symbols() {
+                let junction = Junction::from_symbol(side, symbol, own);
+                assert_eq!(junction.to_symbol(side), Some(symbol));
+            }
+        }
+    }
+}
+
+#[test]
+fn junction_arms() {
+    // ┬ on top with a plain border below.
+    let j = Junction::from_symbol(
+        Side::Top,
+        BorderSymbol::SideInward(Side::Top, BorderType::Double),
+        BorderType::Plain,
+    );
+    assert_eq!(
+        j,
+        Junction::new(
+            None,
+            Some(Arm::own(BorderType::Plain)),
+            Some(Arm::other(Side::Top, BorderType::Double)),
+            Some(Arm::own(BorderType::Plain)),
+        )
+    );
+    assert_eq!(j.count(), 3);
+
+    // bottom left corner of the left side, prolonged downwards.
+    let j = Junction::from_symbol(
+        Side::Left,
+        BorderSymbol::EndCornerProlonged(Side::Left, BorderType::Thick),
+        BorderType::Plain,
+    );
+    assert_eq!(
+        j,
+        Junction::new(
+            Some(Arm::own(BorderType::Plain)),
+            Some(Arm::own(BorderType::Plain)),
+            Some(Arm::other(Side::Left, BorderType::Thick)),
+            None,
+        )
+    );
+    // the same arms are the start corner of the bottom side.
+    assert_eq!(
+        j.to_symbol(Side::Bottom),
+        Some(BorderSymbol::StartCornerAngled(
+            Side::Left,
+            BorderType::Thick
+        ))
+    );
+    // but nothing on the top side.
+    assert_eq!(j.to_symbol(Side::Top), None);
+}
+
+#[test]
+fn junction_no_symbol() {
+    // mixed types for the own arms.
+    let j = Junction::new(
+        None,
+        Some(Arm::own(BorderType::Plain)),
+        Some(Arm::own(BorderType::Double)),
+        None,
+    );
+    assert_eq!(j.to_symbol(Side::Top), None);
+
+    // overlap needs the same border on both arms.
+    let j = Junction::new(
+        None,
+        Some(Arm::other(Side::Top, BorderType::Plain)),
+        None,
+        Some(Arm::other(Side::Bottom, BorderType::Plain)),
+    );
+    assert_eq!(j.to_symbol(Side::Top), None);
+
+    // only own arms of a line.
+    let j = Junction::new(
+        Some(Arm::own(BorderType::Plain)),
+        Some(Arm::own(BorderType::Plain)),
+        Some(Arm::own(BorderType::Plain)),
+        None,
+    );
+    assert_eq!(j.to_symbol(Side::Top), None);
+}
+
+#[test]
+fn junction_set() {
+    for own in TYPES {
+        let set = symbol_set(own);
+        for side in SIDES {
+            for symbol in symbols() {
+                let junction = Junction::from_symbol(side, symbol, own);
+                assert_eq!(set.junction(side, junction), set.symbol(side, symbol));
+            }
+        }
+    }
+
+    // four own arms.
+    let set = symbol_set(BorderType::Plain);
+    let arm = Some(Arm::own(BorderType::Plain));
+    assert_eq!(
+        set.junction(Side::Top, Junction::new(arm, arm, arm, arm)),
+        "┼"
+    );
+}
+
+#[test]
+fn junction_fallback() {
+    let glyph = |v: &str| Junction::from_glyph(v).expect("glyph");
+
+    // box drawing sets draw the arms.
+    let set = symbol_set(BorderType::Plain);
+    for g in ["┬", "├", "╞", "┴", "┤", "└", "┘"] {
+        assert_eq!(set.junction(Side::Top, glyph(g)), g);
+    }
+    assert_eq!(set.junction(Side::Left, glyph("┌")), "┌");
+    assert_eq!(set.junction(Side::Top, glyph("┐")), "┐");
+    assert_eq!(set.junction(Side::Right, glyph("─")), "─");
+    let set = symbol_set(BorderType::Double);
+    assert_eq!(set.junction(Side::Right, glyph("┬")), "┬");
+    assert_eq!(set.junction(Side::Top, glyph("╚")), "╚");
+    assert_eq!(set.junction(Side::Top, glyph("└")), "└");
+
+    // the others use the nearest corner or T.
+    let set = symbol_set(BorderType::QuadrantOutside);
+    assert_eq!(set.junction(Side::Top, glyph("┘")), "▟");
+    assert_eq!(set.junction(Side::Top, glyph("┬")), "▀");
+    assert_eq!(set.junction(Side::Top, glyph("├")), "▌");
+    assert_eq!(set.junction(Side::Top, glyph("│")), "▌");
+    assert_eq!(
+        set.junction(
+            Side::Top,
+            Junction::new(None, Some(Arm::own(BorderType::Plain)), None, None)
+        ),
+        set.line_end(Side::Left)
+    );
+    let set = AsciiSymbolSet;
+    assert_eq!(set.junction(Side::Top, glyph("┬")), "+");
+    assert_eq!(set.junction(Side::Top, glyph("│")), "|");
+}
+
+#[test]
+fn junction_glyph() {
+    let plain = Some(Arm::own(BorderType::Plain));
+    let double = Some(Arm::own(BorderType::Double));
+    assert_eq!(
+        Junction::from_glyph("╞"),
+        Some(Junction::new(plain, double, plain, None))
+    );
+    let rounded = Some(Arm::own(BorderType::Rounded));
+    assert_eq!(
+        Junction::from_glyph("╭"),
+        Some(Junction::new(None, rounded, rounded, None))
+    );
+    let thick = Some(Arm::own(BorderType::Thick));
+    assert_eq!(
+        Junction::from_glyph("╍"),
+        Some(Junction::new(None, thick, None, thick))
+    );
+    let quadrant = Some(Arm::own(BorderType::QuadrantOutside));
+    assert_eq!(
+        Junction::from_glyph("▟"),
+        Some(Junction::new(quadrant, None, None, quadrant))
+    );
+    assert_eq!(
+        Junction::from_glyph("-"),
+        Some(Junction::new(None, plain, None, plain))
+    );
+    assert_eq!(Junction::from_glyph("+").map(|v| v.count()), Some(4));
+    assert_eq!(Junction::from_glyph("╳"), None);
+    assert_eq!(Junction::from_glyph("a"), None);
+}
+
+#[test]
+fn junction_glyph_sets() {
+    // every glyph of the sets can be read back.
+    let mut sets = TYPES.into_iter().map(symbol_set).collect::<Vec<_>>();
+    sets.push(Box::new(AsciiSymbolSet));
+    for set in sets {
+        for side in SIDES {
+            for symbol in symbols() {
+                let glyph = set.symbol(side, symbol);
+                assert!(
+                    Junction::from_glyph(glyph).is_some(),
+                    "{:?} {:?} {:?} {}",
+                    set.border_type(),
+                    side,
+                    symbol,
+                    glyph
+                );
+            }
+        }
+    }
+
+    // box drawing sets keep the arms of the symbol.
+    let arms = |v: Junction| [v.up, v.right, v.down, v.left].map(|v| v.is_some());
+    for own in [BorderType::Plain, BorderType::Thick, BorderType::Double] {
+        let set = symbol_set(own);
+        for side in SIDES {
+            for symbol in symbols() {
+                let foreign = match symbol {
+                    BorderSymbol::StartCornerAngled(_, b)
+                    | BorderSymbol::StartCornerProlonged(_, b)
+                    | BorderSymbol::SideOverlap(_, b)
+                    | BorderSymbol::SideOutward(_, b)
+                    | BorderSymbol::SideInward(_, b)
+                    | BorderSymbol::EndCornerAngled(_, b)
+                    | BorderSymbol::EndCornerProlonged(_, b) => vec![b],
+                    BorderSymbol::StartCornerCrossed(_, b0, _, b1)
+                    | BorderSymbol::SideCrossed(_, b0, _, b1)
+                    | BorderSymbol::EndCornerCrossed(_, b0, _, b1) => vec![b0, b1],
+                    _ => vec![],
+                };
+                if foreign.iter().any(|v| {
+                    !matches!(
+                        v,
+                        BorderType::Plain | BorderType::Thick | BorderType::Double
+                    )
+                }) {
+                    continue;
+                }
+                let glyph = set.symbol(side, symbol);
+                let expected = Junction::from_symbol(side, symbol, own);
+                assert_eq!(
+                    Junction::from_glyph(glyph).map(arms),
+                    Some(arms(expected)),
+                    "{:?} {:?} {:?} {}",
+                    own,
+                    side,
+                    symbol,
+                    glyph
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn junction_glyph_roundtrip() {
+    // every glyph of the box drawing sets is drawn again
+    // from the junction it is read as.
+    for own in [BorderType::Plain, BorderType::Thick, BorderType::Double] {
+        let set = symbol_set(own);
+        for side in SIDES {
+            for symbol in symbols() {
+                let glyph = set.symbol(side, symbol);
+                let junction = Junction::from_glyph(glyph).expect("glyph");
+                assert_eq!(
+                    set.junction(side, junction),
+                    glyph,
+                    "{:?} {:?} {:?}",
+                    own,
+                    side,
+                    symbol
+                );
+            }
+        }
+    }
+}
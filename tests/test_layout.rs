@@ -0,0 +1,305 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_border::{BlockBorder, OverlapPolicy};
+use ratatui_block::block_layout::{merge_touching, ConnectedLayout};
+use ratatui_block::border_symbols::PlainSymbolSet;
+use ratatui_block::{BorderSymbol, BorderSymbolSet, Side};
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf[(x, y)].symbol())
+        .collect()
+}
+
+fn layout() -> (Vec<Rect>, Vec<BorderType>) {
+    (
+        vec![
+            Rect::new(0, 0, 10, 5),
+            Rect::new(9, 0, 11, 3),
+            Rect::new(9, 2, 11, 3),
+            Rect::new(0, 4, 20, 4),
+        ],
+        vec![
+            BorderType::Plain,
+            BorderType::Double,
+            BorderType::Plain,
+            BorderType::Thick,
+        ],
+    )
+}
+
+#[test]
+fn layout_single_pass() {
+    let (areas, borders) = layout();
+    let area = Rect::new(0, 0, 20, 8);
+
+    let mut expect = Buffer::empty(area);
+    for n in (0..areas.len()).rev() {
+        BlockBorder::from_layout(&areas, &borders, n).render(areas[n], &mut expect);
+    }
+
+    let mut buf = Buffer::empty(area);
+    ConnectedLayout::new(&areas, &borders).render(area, &mut buf);
+
+    assert_eq!(buf, expect);
+}
+
+#[test]
+fn layout_clip() {
+    let (areas, borders) = layout();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 8));
+    ConnectedLayout::new(&areas, &borders).render(Rect::new(0, 0, 20, 4), &mut buf);
+
+    for x in 0..20 {
+        assert_eq!(buf[(x, 7)].symbol(), " ");
+    }
+}
+
+#[test]
+fn layout_inner() {
+    let (areas, borders) = layout();
+    let layout = ConnectedLayout::new(&areas, &borders);
+    assert_eq!(layout.len(), 4);
+    assert_eq!(layout.area(1), Rect::new(9, 0, 11, 3));
+    assert_eq!(layout.inner(1), Rect::new(10, 1, 9, 1));
+}
+
+fn touching() -> Vec<Rect> {
+    vec![
+        Rect::new(0, 0, 10, 4),
+        Rect::new(10, 0, 10, 4),
+        Rect::new(0, 4, 20, 4),
+    ]
+}
+
+#[test]
+fn merge_touching_areas() {
+    assert_eq!(
+        merge_touching(&touching()),
+        vec![
+            Rect::new(0, 0, 11, 5),
+            Rect::new(10, 0, 10, 5),
+            Rect::new(0, 4, 20, 4),
+        ]
+    );
+    // not adjacent along the edge
+    assert_eq!(
+        merge_touching(&[Rect::new(0, 0, 10, 4), Rect::new(10, 4, 10, 4)]),
+        vec![Rect::new(0, 0, 10, 4), Rect::new(10, 4, 10, 4)]
+    );
+}
+
+#[test]
+fn touching_layout() {
+    let areas = touching();
+    let borders = [BorderType::Plain; 3];
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 8));
+    ConnectedLayout::from_touching(&areas, &borders).render(buf.area, &mut buf);
+
+    assert_eq!(row(&buf, 0), "┌─────────┬────────┐");
+    assert_eq!(row(&buf, 4), "├─────────┴────────┤");
+    assert_eq!(row(&buf, 7), "└──────────────────┘");
+
+    let mut buf2 = Buffer::empty(Rect::new(0, 0, 20, 8));
+    let merged = merge_touching(&areas);
+    for n in (0..areas.len()).rev() {
+        BlockBorder::from_touching_layout(&areas, &borders, n).render(merged[n], &mut buf2);
+    }
+    assert_eq!(buf, buf2);
+}
+
+fn stacked() -> (Vec<Rect>, Vec<BorderType>) {
+    (
+        vec![Rect::new(0, 0, 10, 3), Rect::new(0, 2, 10, 3)],
+        vec![BorderType::Plain, BorderType::Thick],
+    )
+}
+
+#[test]
+fn overlap_heavier() {
+    let (areas, borders) = stacked();
+    for order in [[0, 1], [1, 0]] {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        for n in order {
+            BlockBorder::from_layout_with(&areas, &borders, n, OverlapPolicy::Heavier)
+                .render(areas[n], &mut buf);
+        }
+        assert_eq!(&row(&buf, 2)[3..27], "━━━━━━━━");
+    }
+}
+
+#[test]
+fn overlap_focused() {
+    let (areas, borders) = stacked();
+    for order in [[0, 1], [1, 0]] {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        for n in order {
+            BlockBorder::from_layout_with(&areas, &borders, n, OverlapPolicy::Focused(0))
+                .render(areas[n], &mut buf);
+        }
+        assert_eq!(&row(&buf, 2)[3..27], "────────");
+    }
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+    ConnectedLayout::new(&areas, &borders)
+        .overlap_policy(OverlapPolicy::Own)
+        .render(buf.area, &mut buf);
+    assert_eq!(&row(&buf, 2)[3..27], "────────");
+}
+
+#[test]
+fn overlap_order() {
+    let areas = [Rect::new(0, 0, 10, 5), Rect::new(9, 0, 10, 5)];
+    let borders = [BorderType::Plain, BorderType::Thick];
+    let policies = [
+        OverlapPolicy::Heavier,
+        OverlapPolicy::Focused(0),
+        OverlapPolicy::Focused(1),
+    ];
+    let ends = [("┲", "┺"), ("┮", "┶"), ("┲", "┺")];
+
+    for (policy, (top, bottom)) in policies.into_iter().zip(ends) {
+        let bufs = [[0, 1], [1, 0]].map(|order| {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 19, 5));
+            for n in order {
+                BlockBorder::from_layout_with(&areas, &borders, n, policy)
+                    .render(areas[n], &mut buf);
+            }
+            buf
+        });
+        assert_eq!(bufs[0], bufs[1]);
+        assert_eq!(bufs[0][(9, 0)].symbol(), top);
+        assert_eq!(bufs[0][(9, 4)].symbol(), bottom);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 19, 5));
+        ConnectedLayout::new(&areas, &borders)
+            .overlap_policy(policy)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, bufs[0]);
+    }
+}
+
+#[test]
+fn overlap_order_layout() {
+    let (areas, borders) = layout();
+    let area = Rect::new(0, 0, 20, 8);
+
+    let border =
+        |n: usize| BlockBorder::from_layout_with(&areas, &borders, n, OverlapPolicy::Heavier);
+
+    let mut forward = Buffer::empty(area);
+    for n in 0..areas.len() {
+        border(n).render(areas[n], &mut forward);
+    }
+    let mut backward = Buffer::empty(area);
+    for n in (0..areas.len()).rev() {
+        border(n).render(areas[n], &mut backward);
+    }
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn focus_outline() {
+    let (areas, borders) = layout();
+    let focus = Style::new().fg(Color::Yellow);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 8));
+    ConnectedLayout::new(&areas, &borders)
+        .focus(Some(2))
+        .focus_style(focus)
+        .render(buf.area, &mut buf);
+
+    let outline = areas[2];
+    for y in 0..8 {
+        for x in 0..20 {
+            let on_outline = outline.contains((x, y).into())
+                && (x == outline.left()
+                    || x == outline.right() - 1
+                    || y == outline.top()
+                    || y == outline.bottom() - 1);
+            assert_eq!(buf[(x, y)].fg == Color::Yellow, on_outline, "{x} {y}");
+        }
+    }
+
+    // same for separately rendered borders, in any order.
+    let mut buf2 = Buffer::empty(Rect::new(0, 0, 20, 8));
+    for n in [2, 0, 1, 3] {
+        let mut block = BlockBorder::from_layout(&areas, &borders, n);
+        block
+            .set_outline_style(areas[n], outline, focus)
+            .expect("fits");
+        block.render(areas[n], &mut buf2);
+    }
+    for y in 0..8 {
+        for x in 0..20 {
+            assert_eq!(buf[(x, y)].fg, buf2[(x, y)].fg, "{x} {y}");
+        }
+    }
+}
+
+#[test]
+fn focus_out_of_range() {
+    let (areas, borders) = layout();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 8));
+    ConnectedLayout::new(&areas, &borders)
+        .focus(Some(areas.len()))
+        .focus_style(Style::new().fg(Color::Yellow))
+        .render(buf.area, &mut buf);
+
+    let mut buf2 = Buffer::empty(Rect::new(0, 0, 20, 8));
+    ConnectedLayout::new(&areas, &borders).render(buf2.area, &mut buf2);
+    assert_eq!(buf, buf2);
+}
+
+// Plain set that marks its crossings.
+#[derive(Debug, Clone)]
+struct CrossingSet;
+
+impl BorderSymbolSet for CrossingSet {
+    fn border_type(&self) -> BorderType {
+        BorderType::Plain
+    }
+
+    fn symbol(&self, side: Side, symbol: BorderSymbol) -> &'static str {
+        PlainSymbolSet.symbol(side, symbol)
+    }
+
+    fn crossing(
+        &self,
+        _top_side: Side,
+        _top: BorderType,
+        _right_side: Side,
+        _right: BorderType,
+        _bottom_side: Side,
+        _bottom: BorderType,
+        _left_side: Side,
+        _left: BorderType,
+    ) -> &'static str {
+        "X"
+    }
+}
+
+#[test]
+fn layout_crossing() {
+    // four areas meet in the middle.
+    let areas = [
+        Rect::new(0, 0, 5, 3),
+        Rect::new(4, 0, 5, 3),
+        Rect::new(0, 2, 5, 3),
+        Rect::new(4, 2, 5, 3),
+    ];
+    let borders = [BorderType::Plain; 4];
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+    BlockBorder::from_layout(&areas, &borders, 0)
+        .border_set(Box::new(CrossingSet))
+        .render(areas[0], &mut buf);
+    assert_eq!(row(&buf, 2), "├───X    ");
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+    BlockBorder::from_layout(&areas, &borders, 1)
+        .border_set(Box::new(CrossingSet))
+        .render(areas[1], &mut buf);
+    assert_eq!(row(&buf, 2), "    X───┤");
+}
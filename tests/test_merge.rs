@@ -0,0 +1,90 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, BorderType, Widget};
+use ratatui_block::merge_borders::MergeBorders;
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn merge_blocks() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 7));
+    Block::bordered().render(Rect::new(0, 0, 5, 4), &mut buf);
+    Block::bordered().render(Rect::new(4, 0, 5, 4), &mut buf);
+    Block::bordered().render(Rect::new(0, 3, 9, 4), &mut buf);
+    MergeBorders::new().render(buf.area, &mut buf);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌───┬───┐",
+            "│   │   │",
+            "│   │   │",
+            "├───┴───┤",
+            "│       │",
+            "│       │",
+            "└───────┘",
+        ]
+    );
+}
+
+#[test]
+fn merge_weight_style() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+    Block::bordered()
+        .border_type(BorderType::Thick)
+        .border_style(Style::new().fg(Color::Red))
+        .render(Rect::new(0, 0, 5, 3), &mut buf);
+    Block::bordered()
+        .border_type(BorderType::Double)
+        .border_style(Style::new().fg(Color::Blue))
+        .render(Rect::new(4, 0, 5, 3), &mut buf);
+    MergeBorders::new().render(buf.area, &mut buf);
+    assert_eq!(rows(&buf), vec!["┏━━━╦═══╗", "┃   ║   ║", "┗━━━╩═══╝"]);
+    assert_eq!(buf[(4, 0)].fg, Color::Blue);
+    assert_eq!(buf[(0, 0)].fg, Color::Red);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+    Block::bordered()
+        .border_type(BorderType::Thick)
+        .render(Rect::new(0, 0, 5, 3), &mut buf);
+    Block::bordered()
+        .border_type(BorderType::Plain)
+        .render(Rect::new(4, 0, 5, 3), &mut buf);
+    MergeBorders::new().render(buf.area, &mut buf);
+    assert_eq!(rows(&buf), vec!["┏━━━┭───┐", "┃   │   │", "┗━━━┵───┘"]);
+}
+
+#[test]
+fn merge_untouched() {
+    // touching but not connected, nothing changes.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 8, 6));
+    Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buf);
+    Block::bordered().render(Rect::new(4, 0, 4, 3), &mut buf);
+    Block::bordered().render(Rect::new(0, 3, 8, 3), &mut buf);
+    let expected = buf.clone();
+    MergeBorders::new().render(buf.area, &mut buf);
+    assert_eq!(buf, expected);
+
+    // text next to a border.
+    let mut buf = Buffer::with_lines(["ab│-+", "──┘ |"]);
+    let expected = buf.clone();
+    MergeBorders::new().render(buf.area, &mut buf);
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn merge_area() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+    Block::bordered().render(Rect::new(0, 0, 5, 3), &mut buf);
+    Block::bordered().render(Rect::new(4, 0, 5, 3), &mut buf);
+    MergeBorders::new().render(Rect::new(4, 0, 5, 3), &mut buf);
+    assert_eq!(rows(&buf), vec!["┌───┌───┐", "│   │   │", "└───└───┘"]);
+}
@@ -0,0 +1,108 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_border::BlockBorder;
+use ratatui_block::border_symbols::{DoubleSymbolSet, PlainSymbolSet};
+use ratatui_block::{BorderSymbol, BorderSymbolSet, Side};
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn plain_corners_to_double() {
+    use BorderSymbol::*;
+    use BorderType::{Double, Plain};
+
+    let set = PlainSymbolSet;
+    assert_eq!(
+        set.symbol(Side::Top, StartCornerAngled(Side::Top, Double)),
+        "╟"
+    );
+    assert_eq!(
+        set.symbol(Side::Top, StartCornerProlonged(Side::Top, Double)),
+        "╤"
+    );
+    assert_eq!(
+        set.symbol(Side::Bottom, EndCornerAngled(Side::Top, Double)),
+        "╢"
+    );
+    assert_eq!(
+        set.symbol(Side::Right, StartCornerProlonged(Side::Top, Double)),
+        "╢"
+    );
+    assert_eq!(
+        set.symbol(Side::Left, EndCornerAngled(Side::Top, Double)),
+        "╧"
+    );
+    assert_eq!(
+        set.symbol(
+            Side::Top,
+            StartCornerCrossed(Side::Top, Double, Side::Top, Plain)
+        ),
+        "╫"
+    );
+    assert_eq!(
+        set.symbol(Side::Top, SideCrossed(Side::Top, Double, Side::Top, Plain)),
+        "╫"
+    );
+}
+
+#[test]
+fn double_corners_to_plain() {
+    use BorderSymbol::*;
+    use BorderType::{Double, Plain};
+
+    let set = DoubleSymbolSet;
+    assert_eq!(
+        set.symbol(Side::Top, StartCornerAngled(Side::Top, Plain)),
+        "╞"
+    );
+    assert_eq!(
+        set.symbol(Side::Top, EndCornerProlonged(Side::Top, Plain)),
+        "╥"
+    );
+    assert_eq!(
+        set.symbol(Side::Bottom, EndCornerAngled(Side::Top, Plain)),
+        "╡"
+    );
+    assert_eq!(
+        set.symbol(Side::Right, StartCornerAngled(Side::Top, Plain)),
+        "╥"
+    );
+    assert_eq!(
+        set.symbol(Side::Left, SideCrossed(Side::Top, Plain, Side::Top, Double)),
+        "╫"
+    );
+}
+
+#[test]
+fn mixed_layout() {
+    let areas = [
+        Rect::new(0, 0, 5, 3),
+        Rect::new(4, 0, 5, 3),
+        Rect::new(0, 2, 9, 3),
+    ];
+    let borders = [BorderType::Double, BorderType::Plain, BorderType::Plain];
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 9, 5));
+    for n in (0..areas.len()).rev() {
+        BlockBorder::from_layout(&areas, &borders, n).render(areas[n], &mut buf);
+    }
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "╔═══╥───┐",
+            "║   ║   │",
+            "╞═══╨───┤",
+            "│       │",
+            "└───────┘"
+        ]
+    );
+}
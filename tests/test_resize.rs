@@ -0,0 +1,192 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_border::{BlockBorder, OverlapPolicy};
+use ratatui_block::{BorderError, BorderSymbol, Side};
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf[(x, y)].symbol())
+        .collect()
+}
+
+fn connected() -> BlockBorder {
+    let areas = [
+        Rect::new(0, 2, 12, 4),
+        Rect::new(2, 0, 3, 3),
+        Rect::new(8, 0, 3, 3),
+    ];
+    BlockBorder::from_layout(&areas, &[BorderType::Plain; 3], 0)
+}
+
+#[test]
+fn render_resized() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 16, 4));
+    connected().render(Rect::new(0, 0, 16, 4), &mut buf);
+
+    assert_eq!(row(&buf, 0), "┌─┴─┴───────┴─┴┐");
+    assert_eq!(row(&buf, 3), "└──────────────┘");
+}
+
+#[test]
+fn resize() {
+    let mut block = connected();
+    let area = Rect::new(3, 1, 9, 5);
+    block.resize(area).expect("fits");
+    assert_eq!(
+        block.get_symbol(area, Position::new(5, 1)),
+        BorderSymbol::SideOutward(Side::Left, BorderType::Plain)
+    );
+    assert_eq!(
+        block.get_symbol(area, Position::new(8, 1)),
+        BorderSymbol::SideOutward(Side::Left, BorderType::Plain)
+    );
+
+    let mut block = connected();
+    assert_eq!(
+        block.resize(Rect::new(0, 0, 8, 4)),
+        Err(BorderError::SizeMismatch)
+    );
+    assert_eq!(
+        block.resize(Rect::new(0, 0, 1, 4)),
+        Err(BorderError::DegenerateArea)
+    );
+
+    // falls back to a regular border.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+    block.render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┌──────┐");
+}
+
+#[test]
+fn set_style_mismatch() {
+    let mut block = connected();
+    let style = Style::new().fg(Color::Red);
+    assert_eq!(
+        block.set_style(Rect::new(0, 0, 8, 4), Position::new(0, 0), style),
+        Err(BorderError::SizeMismatch)
+    );
+    assert_eq!(
+        block.set_side_style(Rect::new(0, 0, 8, 4), Side::Top, style),
+        Err(BorderError::SizeMismatch)
+    );
+
+    // the connections are kept.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+    (&block).render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┌─┴─┴───┴─┴┐");
+    assert_eq!(buf[(0, 0)].fg, Color::Reset);
+}
+
+#[test]
+fn try_symbol() {
+    let area = Rect::new(2, 2, 6, 4);
+    let mut block = BlockBorder::new();
+
+    assert_eq!(
+        block.try_get_symbol(area, Position::new(7, 2)),
+        Ok(BorderSymbol::EndCornerRegular)
+    );
+    assert_eq!(
+        block.try_get_symbol(area, Position::new(4, 2)),
+        Ok(BorderSymbol::SideRegular)
+    );
+    assert_eq!(
+        block.try_get_symbol(area, Position::new(4, 3)),
+        Err(BorderError::OffBorder)
+    );
+    assert_eq!(
+        block.try_get_symbol(Rect::new(0, 0, 1, 5), Position::new(0, 0)),
+        Err(BorderError::DegenerateArea)
+    );
+
+    let junction = BorderSymbol::SideInward(Side::Top, BorderType::Plain);
+    assert_eq!(
+        block.try_set_symbol(area, Position::new(9, 2), junction),
+        Err(BorderError::OffBorder)
+    );
+    assert_eq!(
+        block.try_set_symbol(area, Position::new(4, 2), junction),
+        Ok(())
+    );
+    assert_eq!(
+        block.try_get_symbol(area, Position::new(4, 2)),
+        Ok(junction)
+    );
+
+    // the junction doesn't fit a border of width 3.
+    let small = Rect::new(2, 2, 3, 4);
+    assert_eq!(
+        block.try_set_symbol(small, Position::new(2, 3), junction),
+        Err(BorderError::SizeMismatch)
+    );
+    assert_eq!(
+        block.try_get_symbol(small, Position::new(2, 3)),
+        Err(BorderError::SizeMismatch)
+    );
+    assert_eq!(
+        block.try_get_symbol(area, Position::new(4, 2)),
+        Ok(junction)
+    );
+}
+
+fn overlapped() -> BlockBorder {
+    let areas = [Rect::new(0, 0, 10, 3), Rect::new(0, 2, 10, 3)];
+    BlockBorder::from_layout_with(
+        &areas,
+        &[BorderType::Thick, BorderType::Plain],
+        1,
+        OverlapPolicy::Heavier,
+    )
+}
+
+#[test]
+fn resize_overlap() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+    overlapped().render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┡━━━━━━━━━━━━┩");
+
+    let mut block = overlapped();
+    block.resize(Rect::new(0, 0, 8, 3)).expect("fits");
+    let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+    block.render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┡━━━━━━┩");
+}
+
+#[test]
+fn resize_partial_overlap() {
+    let areas = [Rect::new(0, 2, 12, 4), Rect::new(3, 0, 6, 3)];
+    let mut block = BlockBorder::from_layout_with(
+        &areas,
+        &[BorderType::Plain, BorderType::Thick],
+        0,
+        OverlapPolicy::Heavier,
+    );
+    let style = Style::new().fg(Color::Red);
+    block
+        .set_style(areas[0], Position::new(8, 2), style)
+        .expect("fits");
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+    (&block).render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┌──┺━━━━┹──┐");
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 16, 4));
+    (&block).render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┌──┺━━━━━━━━┹──┐");
+    assert_eq!(buf[(12, 0)].fg, Color::Red);
+    assert_eq!(buf[(11, 0)].fg, Color::Reset);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+    (&block).render(buf.area, &mut buf);
+    assert_eq!(row(&buf, 0), "┌──┺━━┹──┐");
+    assert_eq!(buf[(6, 0)].fg, Color::Red);
+    // changes show up at the cached size too.
+    block
+        .set_style(areas[0], Position::new(0, 2), style)
+        .expect("fits");
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+    (&block).render(buf.area, &mut buf);
+    assert_eq!(buf[(0, 0)].fg, Color::Red);
+}
@@ -0,0 +1,192 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_event::{HandleEvent, MouseOnly, Outcome, Regular};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Direction, Rect};
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::widgets::{BorderType, StatefulWidget};
+use ratatui_block::split_border::{SplitBorder, SplitBorderState};
+
+fn rows(buf: &Buffer) -> Vec<String> {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| {
+            (buf.area.left()..buf.area.right())
+                .map(|x| buf[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn split_render() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+    let mut state = SplitBorderState::new();
+    SplitBorder::new().render(buf.area, &mut buf, &mut state);
+    assert_eq!(rows(&buf), vec!["┌────┬───┐", "│    │   │", "└────┴───┘"]);
+    assert_eq!(state.split(), 5);
+    assert_eq!(state.first_inner(), Rect::new(1, 1, 4, 1));
+    assert_eq!(state.second_inner(), Rect::new(6, 1, 3, 1));
+}
+
+#[test]
+fn split_drag() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+    let mut state = SplitBorderState::new();
+    SplitBorder::new().render(buf.area, &mut buf, &mut state);
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 5, 1),
+        MouseOnly,
+    );
+    assert_eq!(r, Outcome::Changed);
+    assert!(state.dragging);
+    let r = state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), 3, 1),
+        MouseOnly,
+    );
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(state.split(), 3);
+    // limited to keep the border of the first pane.
+    state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), 0, 1),
+        MouseOnly,
+    );
+    assert_eq!(state.split(), 1);
+    state.handle(
+        &mouse(MouseEventKind::Up(MouseButton::Left), 0, 1),
+        MouseOnly,
+    );
+    assert!(!state.dragging);
+
+    // drag not started on the divider.
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 5, 1),
+        MouseOnly,
+    );
+    assert_eq!(r, Outcome::Continue);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+    SplitBorder::new().render(buf.area, &mut buf, &mut state);
+    assert_eq!(rows(&buf), vec!["┌┬───────┐", "││       │", "└┴───────┘"]);
+}
+
+#[test]
+fn split_keys() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 5, 8));
+    let mut state = SplitBorderState::new();
+    SplitBorder::new()
+        .direction(Direction::Vertical)
+        .render(buf.area, &mut buf, &mut state);
+
+    let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::CONTROL));
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        Outcome::Continue
+    );
+    assert_eq!(state.split(), 4);
+
+    state.focused = true;
+    assert_eq!(state.handle(&key(KeyCode::Down), Regular), Outcome::Changed);
+    assert_eq!(state.split(), 5);
+    assert_eq!(state.handle(&key(KeyCode::Down), Regular), Outcome::Changed);
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        Outcome::Unchanged
+    );
+    assert_eq!(state.split(), 6);
+    assert_eq!(
+        state.handle(&key(KeyCode::Left), Regular),
+        Outcome::Continue
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 5, 8));
+    SplitBorder::new()
+        .direction(Direction::Vertical)
+        .render(buf.area, &mut buf, &mut state);
+    assert_eq!(
+        rows(&buf),
+        vec![
+            "┌───┐",
+            "│   │",
+            "│   │",
+            "│   │",
+            "│   │",
+            "│   │",
+            "├───┤",
+            "└───┘"
+        ]
+    );
+}
+
+#[test]
+fn split_hover_drag() {
+    let area = Rect::new(0, 0, 10, 4);
+    let split = SplitBorder::new()
+        .hover_style(Style::new().yellow())
+        .drag_border_type(BorderType::Thick);
+    let mut state = SplitBorderState::new();
+    let mut buf = Buffer::empty(area);
+    (&split).render(area, &mut buf, &mut state);
+
+    let r = state.handle(&mouse(MouseEventKind::Moved, 5, 2), MouseOnly);
+    assert_eq!(r, Outcome::Changed);
+    assert!(state.hover);
+    let r = state.handle(&mouse(MouseEventKind::Moved, 5, 1), MouseOnly);
+    assert_eq!(r, Outcome::Continue);
+
+    let mut buf = Buffer::empty(area);
+    (&split).render(area, &mut buf, &mut state);
+    assert_eq!(
+        rows(&buf),
+        vec!["┌────┬───┐", "│    │   │", "│    │   │", "└────┴───┘"]
+    );
+    assert_eq!(buf[(5, 1)].fg, Color::Yellow);
+    assert_eq!(buf[(4, 1)].fg, Color::Reset);
+
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 5, 1),
+        MouseOnly,
+    );
+    let mut buf = Buffer::empty(area);
+    (&split).render(area, &mut buf, &mut state);
+    assert_eq!(
+        rows(&buf),
+        vec!["┌────┰───┐", "│    ┃   │", "│    ┃   │", "└────┸───┘"]
+    );
+    assert_eq!(buf[(5, 0)].fg, Color::Yellow);
+
+    state.handle(
+        &mouse(MouseEventKind::Up(MouseButton::Left), 2, 1),
+        MouseOnly,
+    );
+    assert!(!state.dragging);
+    assert!(!state.hover);
+}
+
+#[test]
+fn split_hover_ends() {
+    let area = Rect::new(0, 0, 10, 4);
+    let mut state = SplitBorderState::new();
+    let mut buf = Buffer::empty(area);
+    (&SplitBorder::new()).render(area, &mut buf, &mut state);
+
+    // the junctions at the ends belong to the shared edge.
+    state.handle(&mouse(MouseEventKind::Moved, 5, 0), MouseOnly);
+    assert!(state.hover);
+    state.handle(&mouse(MouseEventKind::Moved, 5, 3), MouseOnly);
+    assert!(state.hover);
+    state.handle(&mouse(MouseEventKind::Moved, 4, 0), MouseOnly);
+    assert!(!state.hover);
+    state.handle(&mouse(MouseEventKind::Moved, 6, 3), MouseOnly);
+    assert!(!state.hover);
+}
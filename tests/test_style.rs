@@ -0,0 +1,129 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+use ratatui_block::block_border::BlockBorder;
+use ratatui_block::border_style::{perimeter_index, perimeter_len, LinearGradient, Marquee};
+use ratatui_block::Side;
+
+#[test]
+fn style_cell() {
+    let area = Rect::new(2, 1, 6, 4);
+    let mut block = BlockBorder::new().border_style(Style::new().fg(Color::White));
+    block
+        .set_style(area, Position::new(7, 2), Style::new().bg(Color::Red))
+        .expect("fits");
+    assert_eq!(
+        block.get_style(area, Position::new(7, 2)),
+        Style::new().bg(Color::Red)
+    );
+    assert_eq!(block.get_style(area, Position::new(2, 2)), Style::new());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 6));
+    block.render(area, &mut buf);
+    assert_eq!(buf[(7, 2)].fg, Color::White);
+    assert_eq!(buf[(7, 2)].bg, Color::Red);
+    assert_eq!(buf[(7, 3)].bg, Color::Reset);
+}
+
+#[test]
+fn style_sides() {
+    let area = Rect::new(0, 0, 8, 4);
+    let mut block = BlockBorder::new();
+    block
+        .set_side_style(area, Side::Left, Style::new().fg(Color::Green))
+        .expect("fits");
+    block
+        .set_style_range(area, Side::Top, 2..5, Style::new().fg(Color::Blue))
+        .expect("fits");
+    block
+        .set_style_range(area, Side::Bottom, 6..20, Style::new().fg(Color::Red))
+        .expect("fits");
+
+    let mut buf = Buffer::empty(area);
+    block.render(area, &mut buf);
+
+    for y in 0..4 {
+        assert_eq!(buf[(0, y)].fg, Color::Green);
+    }
+    let top = (0..8).map(|x| buf[(x, 0)].fg).collect::<Vec<_>>();
+    assert_eq!(
+        top,
+        vec![
+            Color::Green,
+            Color::Reset,
+            Color::Blue,
+            Color::Blue,
+            Color::Blue,
+            Color::Reset,
+            Color::Reset,
+            Color::Reset,
+        ]
+    );
+    assert_eq!(buf[(5, 3)].fg, Color::Reset);
+    assert_eq!(buf[(6, 3)].fg, Color::Red);
+    assert_eq!(buf[(7, 3)].fg, Color::Red);
+}
+
+#[test]
+fn perimeter() {
+    let area = Rect::new(1, 1, 4, 3);
+    let order = [
+        (1, 1),
+        (2, 1),
+        (3, 1),
+        (4, 1),
+        (4, 2),
+        (4, 3),
+        (3, 3),
+        (2, 3),
+        (1, 3),
+        (1, 2),
+    ];
+    for (i, (x, y)) in order.iter().enumerate() {
+        assert_eq!(perimeter_index(area, Position::new(*x, *y)), i);
+    }
+    assert_eq!(perimeter_len(area), 10);
+}
+
+#[test]
+fn gradient() {
+    let area = Rect::new(0, 0, 5, 3);
+    let mut buf = Buffer::empty(area);
+    BlockBorder::new()
+        .border_style_provider(Box::new(LinearGradient::new(
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(200, 100, 0),
+        )))
+        .render(area, &mut buf);
+
+    assert_eq!(buf[(0, 0)].fg, Color::Rgb(0, 0, 0));
+    assert_eq!(buf[(2, 2)].fg, Color::Rgb(100, 50, 0));
+    assert_eq!(buf[(4, 1)].fg, Color::Rgb(200, 100, 0));
+}
+
+#[test]
+fn marquee() {
+    let area = Rect::new(0, 0, 4, 3);
+    let on = Style::new().fg(Color::Red);
+
+    let mut buf = Buffer::empty(area);
+    let mut block = BlockBorder::new().border_style_provider(Box::new(
+        Marquee::new().on_style(on).dash(1).gap(2).frame(1),
+    ));
+    // per-cell styles and the provider work together.
+    block
+        .set_style(area, Position::new(0, 2), Style::new().bg(Color::Blue))
+        .expect("fits");
+    block.render(area, &mut buf);
+
+    let red = [(0, 0), (1, 0), (3, 1), (1, 2)];
+    for y in 0..3 {
+        for x in 0..4 {
+            if x == 0 || x == 3 || y == 0 || y == 2 {
+                assert_eq!(buf[(x, y)].fg == Color::Red, red.contains(&(x, y)));
+            }
+        }
+    }
+    assert_eq!(buf[(0, 2)].bg, Color::Blue);
+}
@@ -0,0 +1,95 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::text::Line;
+use ratatui::widgets::{BorderType, Widget};
+use ratatui_block::block_border::BlockBorder;
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf[(x, y)].symbol())
+        .collect()
+}
+
+fn layout() -> (Vec<Rect>, Vec<BorderType>) {
+    let area = Rect::new(0, 4, 30, 5);
+    let above = Rect::new(6, 0, 6, 5);
+    (vec![area, above], vec![BorderType::Plain, BorderType::Plain])
+}
+
+#[test]
+fn title_plain() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+    BlockBorder::new()
+        .title("Title")
+        .title_bottom(Line::from("End").alignment(Alignment::Right))
+        .render(buf.area, &mut buf);
+
+    assert_eq!(row(&buf, 0), "┌Title───────────────────────┐");
+    assert_eq!(row(&buf, 4), "└─────────────────────────End┘");
+}
+
+#[test]
+fn title_around_junction() {
+    let (areas, borders) = layout();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 9));
+    BlockBorder::from_layout(&areas, &borders, 0)
+        .title("ab")
+        .title("Hi there")
+        .render(areas[0], &mut buf);
+
+    assert_eq!(row(&buf, 4), "┌ab───┴────┴Hi there─────────┐");
+}
+
+#[test]
+fn title_center_junction() {
+    let (areas, borders) = layout();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 9));
+    BlockBorder::from_layout(&areas, &borders, 0)
+        .title_alignment(Alignment::Center)
+        .title("abcd")
+        .render(areas[0], &mut buf);
+
+    assert_eq!(row(&buf, 4), "┌─────┴────┴─abcd────────────┐");
+}
+
+#[test]
+fn title_truncated() {
+    let (areas, borders) = layout();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 9));
+    let areas = [Rect::new(0, 4, 20, 5), areas[1]];
+    BlockBorder::from_layout(&areas, &borders, 0)
+        .title("A long title")
+        .render(areas[0], &mut buf);
+
+    assert_eq!(row(&buf, 4), "┌─────┴────┴A long ┐");
+}
+
+fn column(buf: &Buffer, x: u16) -> String {
+    (buf.area.top()..buf.area.bottom())
+        .map(|y| buf[(x, y)].symbol())
+        .collect()
+}
+
+#[test]
+fn title_vertical() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 8));
+    BlockBorder::new()
+        .title_left("abc")
+        .title_right(Line::from("xy").alignment(Alignment::Right))
+        .render(buf.area, &mut buf);
+
+    assert_eq!(column(&buf, 0), "┌abc│││└");
+    assert_eq!(column(&buf, 9), "┐││││xy┘");
+}
+
+#[test]
+fn title_vertical_junction() {
+    let area = Rect::new(6, 0, 6, 8);
+    let left = Rect::new(0, 3, 7, 3);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 12, 8));
+    BlockBorder::from_layout(&[area, left], &[BorderType::Plain, BorderType::Plain], 0)
+        .title_left("abcdef")
+        .render(area, &mut buf);
+
+    assert_eq!(column(&buf, 6), "┌ab┤c┤d└");
+}